@@ -1,8 +1,282 @@
+#![allow(clippy::enum_variant_names, clippy::vec_box, clippy::box_collection, clippy::while_let_loop, clippy::single_match, clippy::len_zero, clippy::needless_return, clippy::new_ret_no_self, clippy::bool_assert_comparison)]
+
 pub(crate) mod parser;
 
+use crate::parser::python_core_block_parser::BlockGrammarRules;
 use crate::parser::python_core_parser::PythonCoreParser;
+use crate::parser::python_core_tokenizer::{LexerMethods, PythonCoreTokenizer};
+
+pub use crate::parser::parser_options::{ParserOptions, StartRule};
+pub use crate::parser::python_version::{Feature, PythonVersion};
+pub use crate::parser::diagnostic::{Diagnostic, DiagnosticCode, Fix, Label, Severity};
+pub use crate::parser::syntax_nodes::{ParameterKind, SyntaxNode};
+pub use crate::parser::token_nodes::Token;
+pub use crate::parser::trivia_nodes::Trivia;
+
+/// Syntax tree and diagnostics from one of the parse functions.
+pub struct ParseResult {
+    /// Syntax tree, missing when parsing stopped at an error. In a module a statement with errors
+    /// becomes an error node with its tokens and the parts that did parse.
+    pub tree: Option<Box<SyntaxNode>>,
+    /// Errors from decoding, tokenizing and parsing, in the order found. Spans are char offsets into
+    /// the source text, or into the decoded text for bytes.
+    pub errors: Vec<Box<Diagnostic>>,
+    /// Warnings like invalid escape sequences, these never stop parsing.
+    pub warnings: Vec<Box<Diagnostic>>,
+    /// Encoding the source was decoded from, always "utf-8" for text.
+    pub encoding: &'static str,
+}
+
+impl ParseResult {
+    pub fn is_ok(&self) -> bool {
+        self.tree.is_some() && self.errors.is_empty()
+    }
+}
+
+/// Parse source text from the start rule and for the target version given in the options.
+pub fn parse(source: &str, options: &ParserOptions) -> ParseResult {
+    let mut parser = PythonCoreParser::with_options(source, options);
+    let tree = parse_start_rule(&mut parser, options.start);
+    finish(parser, tree)
+}
+
+/// Parse raw bytes, decoded as given by a BOM or PEP 263 coding declaration.
+pub fn parse_bytes(source: &[u8], options: &ParserOptions) -> ParseResult {
+    let mut parser = PythonCoreParser::from_bytes(source, options);
+    let tree = parse_start_rule(&mut parser, options.start);
+    finish(parser, tree)
+}
+
+/// Parse a module, i.e. the contents of a '.py' file.
+pub fn parse_module(source: &str) -> ParseResult {
+    parse(source, &ParserOptions::default())
+}
+
+/// Parse a module from raw bytes, decoded as given by a BOM or PEP 263 coding declaration.
+pub fn parse_module_bytes(source: &[u8]) -> ParseResult {
+    parse_bytes(source, &ParserOptions::default())
+}
+
+/// Parse a single expression, as for 'eval()'.
+pub fn parse_expression(source: &str) -> ParseResult {
+    parse(source, &ParserOptions { start: StartRule::Eval, ..ParserOptions::default() })
+}
+
+/// Parse one interactive statement, as entered at the REPL prompt.
+pub fn parse_interactive(source: &str) -> ParseResult {
+    parse(source, &ParserOptions { start: StartRule::Single, ..ParserOptions::default() })
+}
+
+/// Parse a function signature type comment, i.e. '(int, str) -> bool'.
+pub fn parse_func_type(source: &str) -> ParseResult {
+    parse(source, &ParserOptions { start: StartRule::FuncType, ..ParserOptions::default() })
+}
+
+/// Tokenize source text, ending with the EOF token. Problems show up as 'BadToken' in the tokens.
+pub fn tokenize(source: &str) -> Vec<Token> {
+    PythonCoreTokenizer::new(source).collect()
+}
+
+fn parse_start_rule(parser: &mut PythonCoreParser, start: StartRule) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+    match start {
+        StartRule::File => parser.parse_file_input(),
+        StartRule::Eval => parser.parse_eval_input(),
+        StartRule::Single => parser.parse_single_input(),
+        StartRule::FuncType => parser.parse_func_type_input()
+    }
+}
+
+fn finish(parser: PythonCoreParser, tree: Result<Box<SyntaxNode>, Box<Diagnostic>>) -> ParseResult {
+    let mut errors = parser.lexer.errors;
+
+    let tree = match tree {
+        Ok(node) => Some(node),
+        Err(error) => {
+            errors.push(error);
+            None
+        }
+    };
+
+    ParseResult { tree, errors, warnings: parser.lexer.warnings, encoding: parser.lexer.encoding }
+}
+
+// Unittests for public parse functions ////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::{parse, parse_expression, parse_func_type, parse_interactive, parse_module, parse_module_bytes, tokenize, DiagnosticCode, Feature, ParserOptions, PythonVersion, Severity, StartRule, SyntaxNode, Token};
+
+    #[test]
+    fn parse_module_with_statements() {
+        let result = parse_module("import os\nx = os.getcwd()\n");
+
+        assert!(result.is_ok());
+        assert!(matches!(result.tree.as_deref(), Some(SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ )) if nodes.len() == 2));
+        assert_eq!(result.encoding, "utf-8");
+    }
+
+    #[test]
+    fn parse_module_with_error() {
+        let result = parse_module("x = = 1\n");
+
+        assert!(!result.is_ok());
+        assert!(matches!(result.tree.as_deref(), Some(SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ )) if matches!(&*nodes[0], SyntaxNode::ErrorNode( .. ))));
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!((result.errors[0].start, result.errors[0].end), (4, 5));
+    }
+
+    #[test]
+    fn parse_module_diagnostic_codes() {
+        let result = parse_module("if x\n    y = (1, 2]\n");
+
+        assert_eq!(result.errors.iter().map(|error| error.code).collect::<Vec<DiagnosticCode>>(), vec![DiagnosticCode::ExpectedColon, DiagnosticCode::UnmatchedBracket, DiagnosticCode::ExpectedClosingBracket]);
+        assert!(result.errors.iter().all(|error| error.severity == Severity::Error));
+        assert_eq!(result.errors.iter().filter(|error| error.code == DiagnosticCode::ExpectedColon).count(), 1);
+    }
+
+    #[test]
+    fn parse_module_from_bytes() {
+        let result = parse_module_bytes(b"# -*- coding: latin-1 -*-\nx = '\xe9'\n");
+
+        assert!(result.is_ok());
+        assert_eq!(result.encoding, "iso-8859-1");
+    }
+
+    #[test]
+    fn parse_other_start_rules() {
+        assert!(matches!(parse_expression("a + b\n").tree.as_deref(), Some(SyntaxNode::EvalInputStmtNode( .. ))));
+        assert!(matches!(parse_interactive("x = 1\n").tree.as_deref(), Some(SyntaxNode::SingleInputStmtNode( .. ))));
+        assert!(matches!(parse_func_type("(int, str) -> bool").tree.as_deref(), Some(SyntaxNode::FuncTypeInputStmtNode( .. ))));
+        assert!(!parse_expression("x = 1\n").is_ok());
+    }
+
+    #[test]
+    fn tokenize_source() {
+        let symbols = tokenize("a + 1\n");
+
+        assert_eq!(symbols.len(), 5);
+        assert!(matches!(symbols[0], Token::NameToken(0, 1, _ , _ )));
+        assert!(matches!(symbols[4], Token::EofToken( .. )));
+    }
+
+    #[test]
+    fn parse_with_options() {
+        let options = ParserOptions { start: StartRule::Eval, ..ParserOptions::default() };
+        assert!(matches!(parse("a + b\n", &options).tree.as_deref(), Some(SyntaxNode::EvalInputStmtNode( .. ))));
+
+        let options = ParserOptions { type_comments: true, ..ParserOptions::default() };
+        let result = parse("def f(a):  # type: (int) -> None\n    pass\n", &options);
+        assert!(matches!(result.tree.as_deref(), Some(SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ )) if matches!(&*nodes[0], SyntaxNode::FuncDefinitionNode( .. , Some( _ )))));
+
+        let options = ParserOptions { target: PythonVersion::Python313, ..ParserOptions::default() };
+        let result = parse("x = t'{y}'\nmatch x:\n    case _:\n        pass\n", &options);
+        assert!(result.tree.is_some());
+        assert_eq!(result.errors.len(), 1);
+
+        let options = ParserOptions { target: PythonVersion::Python38, features: vec![(Feature::PatternMatching, true), (Feature::TemplateStrings, false)], ..ParserOptions::default() };
+        let result = parse("x = t'{y}'\nmatch x:\n    case _:\n        pass\n", &options);
+        assert_eq!(result.errors.iter().map(|error| error.message.as_str()).collect::<Vec<&str>>(), vec!["template strings are only supported in Python 3.14 and greater, target is Python 3.8"]);
+    }
+
+    #[test]
+    fn parse_module_if_elif_else() {
+        let result = parse_module("if a:\n    pass\nelse:\n    pass\n");
+        assert!(result.errors.is_empty());
+        assert!(matches!(result.tree.as_deref(), Some(SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ ))
+            if matches!(&*nodes[0], SyntaxNode::IfStmtNode( _ , _ , _ , _ , _ , _ , elifs , Some(else_part) ) if elifs.is_empty() && matches!(&**else_part, SyntaxNode::ElseStmtNode(15, _ , _ , _ , _ )))));
+
+        let result = parse_module("if a:\n    pass\nelif b:\n    pass\nelif c:\n    pass\nelse:\n    pass\n");
+        assert!(result.errors.is_empty());
+        assert!(matches!(result.tree.as_deref(), Some(SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ ))
+            if matches!(&*nodes[0], SyntaxNode::IfStmtNode( _ , _ , _ , _ , _ , _ , elifs , Some(else_part) )
+                if elifs.len() == 2 && matches!(&*elifs[0], SyntaxNode::ElifStmtNode(15, _ , _ , _ , _ , _ )) && matches!(&*elifs[1], SyntaxNode::ElifStmtNode(32, _ , _ , _ , _ , _ ))
+                    && matches!(&**else_part, SyntaxNode::ElseStmtNode(49, _ , _ , _ , _ )))));
+    }
+
+    #[test]
+    fn parse_module_keeps_source_order() {
+        let result = parse_module("x = [1, 2, 3]\n");
+        assert!(result.errors.is_empty());
+        assert!(matches!(result.tree.as_deref(), Some(SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ ))
+            if matches!(&*nodes[0], SyntaxNode::SimpleStmtNode( _ , _ , nodes , _ , _ )
+                if matches!(&*nodes[0], SyntaxNode::AssignmentStmtNode( _ , _ , _ , nodes , _ )
+                    if matches!(&*nodes[0], SyntaxNode::AssignmentElementStmtNode( _ , _ , _ , right )
+                        if matches!(&**right, SyntaxNode::TestListStarExprStmtNode( _ , _ , nodes , _ )
+                            if matches!(&*nodes[0], SyntaxNode::ListExprNode( _ , _ , _ , Some(list) , _ )
+                                if matches!(&**list, SyntaxNode::TestListComprehensionExprNode( _ , _ , nodes , separators )
+                                    if matches!(&*nodes[0], SyntaxNode::NumberExprNode(5, 6, _ )) && matches!(&*nodes[2], SyntaxNode::NumberExprNode(11, 12, _ ))
+                                        && matches!(&*separators[0], Token::CommaToken(6, 7, _ ))))))))));
+
+        let result = parse_module("global a, b\n");
+        assert!(matches!(result.tree.as_deref(), Some(SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ ))
+            if matches!(&*nodes[0], SyntaxNode::SimpleStmtNode( _ , _ , nodes , _ , _ )
+                if matches!(&*nodes[0], SyntaxNode::GlobalStmtNode( _ , _ , _ , names , _ ) if matches!(&*names[0], SyntaxNode::NameExprNode(7, 8, _ ))))));
+
+        let result = parse_module("from . ... import x\n");
+        assert!(matches!(result.tree.as_deref(), Some(SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ ))
+            if matches!(&*nodes[0], SyntaxNode::SimpleStmtNode( _ , _ , nodes , _ , _ )
+                if matches!(&*nodes[0], SyntaxNode::ImportFromStmtNode( _ , _ , _ , dots , .. ) if matches!(&*dots[0], Token::PeriodToken(5, 6, _ ))))));
+
+        let result = parse_module("@a\n@b\ndef f():\n    pass\n");
+        assert!(result.errors.is_empty());
+        assert!(matches!(result.tree.as_deref(), Some(SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ ))
+            if matches!(&*nodes[0], SyntaxNode::DecoratedStmtNode( _ , _ , decorators , _ )
+                if matches!(&**decorators, SyntaxNode::DecoratorsStmtNode( _ , _ , nodes ) if matches!(&*nodes[0], SyntaxNode::DecoratorStmtNode(0, .. )) && matches!(&*nodes[1], SyntaxNode::DecoratorStmtNode(3, .. ))))));
+    }
+
+    #[test]
+    fn parse_module_async_for() {
+        let result = parse_module("async def f():\n    async for x in y:\n        pass\n");
+        assert!(result.errors.is_empty());
+    }
+
+    #[test]
+    fn parse_module_with_invalid_except_star() {
+        let result = parse_module("try:\n    pass\nexcept ValueError:\n    pass\nexcept* TypeError:\n    pass\nx = 1\n");
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!((result.errors[0].code, result.errors[0].start), (DiagnosticCode::InvalidExceptClause, 42));
+        assert_eq!(result.errors[0].message, "cannot have both 'except' and 'except*' on the same 'try'");
+        assert!(matches!(result.tree.as_deref(), Some(SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ )) if nodes.len() == 2 && matches!(&*nodes[0], SyntaxNode::ErrorNode( .. ))));
+
+        let result = parse_module("try:\n    pass\nexcept*:\n    pass\nfinally:\n    pass\n");
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].message, "expected one or more exception types");
+    }
+
+    #[test]
+    fn parse_module_with_unparenthesized_except_types_and_as() {
+        let result = parse_module("try:\n    pass\nexcept A, B as e:\n    pass\nelse:\n    pass\n");
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!((result.errors[0].code, result.errors[0].start), (DiagnosticCode::InvalidExceptClause, 21));
+        assert_eq!(result.errors[0].message, "multiple exception types must be parenthesized when using 'as'");
+    }
+
+    #[test]
+    fn parse_module_with_errors_in_header_and_body() {
+        let result = parse_module("def f(x y):\n    z = = 1\n    w = 2\n");
+        assert_eq!(result.errors.len(), 2);
+        assert_eq!((result.errors[0].code, result.errors[0].start, result.errors[0].end), (DiagnosticCode::ExpectedClosingBracket, 8, 9));
+        assert_eq!((result.errors[1].start, result.errors[1].end), (20, 21));
+    }
+
+    #[test]
+    fn parse_module_with_invalid_case_pattern() {
+        let result = parse_module("match x:\n    case Point(x=0, 1):\n        pass\n    case _:\n        pass\n");
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].message, "positional patterns follow keyword patterns");
+    }
+
+    #[test]
+    fn parse_module_with_unclosed_class_bases() {
+        let result = parse_module("class A(B:\n    def f(self): pass\n    def g(self): pass\n");
+        assert_eq!(result.errors.iter().map(|error| (error.start, error.message.as_str())).collect::<Vec<(u32, &str)>>(), vec![(7, "'(' was never closed"), (9, "Expecting ')' in 'class' statement!")]);
+        assert!(matches!(&result.errors[1].fixes[..], [fix] if (fix.start, fix.end) == (9, 9) && fix.replacement == ")"));
+    }
 
-pub(crate) fn parse_stuff() -> Result<(), ()> {
-    let parser = PythonCoreParser::new();
-    Ok(())
+    #[test]
+    fn parse_module_with_missing_expression() {
+        let result = parse_module("x = :\n");
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!((result.errors[0].code, result.errors[0].start), (DiagnosticCode::ExpectedExpression, 4));
+        assert!(["NAME", "FSTRING_START", "not", "lambda", "*", "yield"].iter().all(|token| result.errors[0].expected.contains(&String::from(*token))));
+    }
 }
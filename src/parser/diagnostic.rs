@@ -0,0 +1,193 @@
+/// How serious a diagnostic is, warnings never stop the source from compiling.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Stable identity of a diagnostic, written as 'PY001 expected-colon'. Codes are never reused.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DiagnosticCode {
+    ExpectedColon,
+    ExpectedClosingBracket,
+    ExpectedNewline,
+    ExpectedName,
+    ExpectedIndent,
+    ExpectedExpression,
+    ExpectedToken,
+    InvalidParameters,
+    InvalidPattern,
+    InvalidFString,
+    InvalidString,
+    UnterminatedString,
+    InvalidNumber,
+    InvalidCharacter,
+    Indentation,
+    UnmatchedBracket,
+    UnexpectedEof,
+    UnsupportedFeature,
+    Encoding,
+    InvalidExceptClause,
+}
+
+impl DiagnosticCode {
+    /// Code as 'PY001'.
+    pub fn code(&self) -> &'static str {
+        match self {
+            DiagnosticCode::ExpectedColon => "PY001",
+            DiagnosticCode::ExpectedClosingBracket => "PY002",
+            DiagnosticCode::ExpectedNewline => "PY003",
+            DiagnosticCode::ExpectedName => "PY004",
+            DiagnosticCode::ExpectedIndent => "PY005",
+            DiagnosticCode::ExpectedExpression => "PY006",
+            DiagnosticCode::ExpectedToken => "PY007",
+            DiagnosticCode::InvalidParameters => "PY008",
+            DiagnosticCode::InvalidPattern => "PY009",
+            DiagnosticCode::InvalidFString => "PY010",
+            DiagnosticCode::InvalidString => "PY011",
+            DiagnosticCode::UnterminatedString => "PY012",
+            DiagnosticCode::InvalidNumber => "PY013",
+            DiagnosticCode::InvalidCharacter => "PY014",
+            DiagnosticCode::Indentation => "PY015",
+            DiagnosticCode::UnmatchedBracket => "PY016",
+            DiagnosticCode::UnexpectedEof => "PY017",
+            DiagnosticCode::UnsupportedFeature => "PY018",
+            DiagnosticCode::Encoding => "PY019",
+            DiagnosticCode::InvalidExceptClause => "PY020"
+        }
+    }
+
+    /// Name as 'expected-colon'.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DiagnosticCode::ExpectedColon => "expected-colon",
+            DiagnosticCode::ExpectedClosingBracket => "expected-closing-bracket",
+            DiagnosticCode::ExpectedNewline => "expected-newline",
+            DiagnosticCode::ExpectedName => "expected-name",
+            DiagnosticCode::ExpectedIndent => "expected-indent",
+            DiagnosticCode::ExpectedExpression => "expected-expression",
+            DiagnosticCode::ExpectedToken => "expected-token",
+            DiagnosticCode::InvalidParameters => "invalid-parameters",
+            DiagnosticCode::InvalidPattern => "invalid-pattern",
+            DiagnosticCode::InvalidFString => "invalid-fstring",
+            DiagnosticCode::InvalidString => "invalid-string",
+            DiagnosticCode::UnterminatedString => "unterminated-string",
+            DiagnosticCode::InvalidNumber => "invalid-number",
+            DiagnosticCode::InvalidCharacter => "invalid-character",
+            DiagnosticCode::Indentation => "indentation",
+            DiagnosticCode::UnmatchedBracket => "unmatched-bracket",
+            DiagnosticCode::UnexpectedEof => "unexpected-eof",
+            DiagnosticCode::UnsupportedFeature => "unsupported-feature",
+            DiagnosticCode::Encoding => "encoding",
+            DiagnosticCode::InvalidExceptClause => "invalid-except-clause"
+        }
+    }
+}
+
+/// Other source text related to a diagnostic, as the opening bracket of an unmatched closing bracket.
+#[derive(Clone, Debug)]
+pub struct Label {
+    pub start: u32,
+    pub end: u32,
+    pub message: String,
+}
+
+/// Edit that fixes a diagnostic, replacing start..end with the replacement text.
+#[derive(Clone, Debug)]
+pub struct Fix {
+    pub start: u32,
+    pub end: u32,
+    pub replacement: String,
+    pub message: String,
+}
+
+/// Problem found in the source, with start and end offset of the text it is about, end being exclusive.
+/// Offsets here and in labels and fixes count chars of the source text, not bytes.
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub code: DiagnosticCode,
+    pub severity: Severity,
+    pub start: u32,
+    pub end: u32,
+    pub message: String,
+    pub labels: Vec<Label>,
+    pub fixes: Vec<Fix>,
+    /// Tokens that would have been accepted where the error is, as their text or a class like 'NAME'.
+    pub expected: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn error(code: DiagnosticCode, start: u32, end: u32, message: String) -> Self {
+        Diagnostic { code, severity: Severity::Error, start, end, message, labels: Vec::new(), fixes: Vec::new(), expected: Vec::new() }
+    }
+
+    pub fn warning(code: DiagnosticCode, start: u32, end: u32, message: String) -> Self {
+        Diagnostic { code, severity: Severity::Warning, start, end, message, labels: Vec::new(), fixes: Vec::new(), expected: Vec::new() }
+    }
+
+    pub fn with_label(mut self, start: u32, end: u32, message: &str) -> Self {
+        self.labels.push(Label { start, end, message: String::from(message) });
+        self
+    }
+
+    pub fn with_fix(mut self, start: u32, end: u32, replacement: &str, message: &str) -> Self {
+        self.fixes.push(Fix { start, end, replacement: String::from(replacement), message: String::from(message) });
+        self
+    }
+
+    pub fn with_expected(mut self, expected: &[&str]) -> Self {
+        self.expected = expected.iter().map(|text| String::from(*text)).collect();
+        self
+    }
+
+    /// Expected tokens for display as "expected one of `)`, `,`, `for`", none when not known.
+    pub fn expected_message(&self) -> Option<String> {
+        match self.expected.as_slice() {
+            [] => None,
+            [text] => Some(format!("expected `{}`", text)),
+            texts => Some(format!("expected one of {}", texts.iter().map(|text| format!("`{}`", text)).collect::<Vec<String>>().join(", ")))
+        }
+    }
+}
+
+// Unittests for diagnostics ///////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::parser::diagnostic::{Diagnostic, DiagnosticCode, Severity};
+
+    #[test]
+    fn diagnostic_code_and_name() {
+        assert_eq!(DiagnosticCode::ExpectedColon.code(), "PY001");
+        assert_eq!(DiagnosticCode::ExpectedColon.name(), "expected-colon");
+        assert_eq!(DiagnosticCode::Encoding.code(), "PY019");
+        assert_eq!(DiagnosticCode::InvalidExceptClause.name(), "invalid-except-clause");
+        assert_eq!(DiagnosticCode::UnmatchedBracket.name(), "unmatched-bracket");
+    }
+
+    #[test]
+    fn diagnostic_with_label_and_fix() {
+        let diagnostic = Diagnostic::error(DiagnosticCode::UnmatchedBracket, 2, 3, String::from("closing parenthesis ']' does not match opening parenthesis '('"))
+            .with_label(0, 1, "opening parenthesis here")
+            .with_fix(2, 3, ")", "Replace with ')'");
+
+        assert_eq!(diagnostic.severity, Severity::Error);
+        assert_eq!((diagnostic.start, diagnostic.end), (2, 3));
+        assert_eq!(diagnostic.labels[0].message, "opening parenthesis here");
+        assert_eq!(diagnostic.fixes[0].replacement, ")");
+
+        let diagnostic = Diagnostic::warning(DiagnosticCode::InvalidNumber, 1, 1, String::from("invalid decimal literal"));
+        assert_eq!(diagnostic.severity, Severity::Warning);
+    }
+
+    #[test]
+    fn diagnostic_expected_message() {
+        let diagnostic = Diagnostic::error(DiagnosticCode::ExpectedToken, 0, 1, String::from("Expecting 'in' in comprehension 'for' expression!"));
+        assert_eq!(diagnostic.expected_message(), None);
+
+        let diagnostic = diagnostic.with_expected(&["in"]);
+        assert_eq!(diagnostic.expected_message().unwrap(), "expected `in`");
+
+        let diagnostic = diagnostic.with_expected(&[")", ",", "for"]);
+        assert_eq!(diagnostic.expected_message().unwrap(), "expected one of `)`, `,`, `for`");
+    }
+}
@@ -0,0 +1,74 @@
+// FIRST and FOLLOW sets of the grammar rules, used for the tokens listed as expected in syntax errors.
+// Tokens are written as their text, or as 'NAME', 'NUMBER', 'STRING', 'FSTRING_START', 'TSTRING_START',
+// 'NEWLINE', 'INDENT', 'DEDENT' and 'EOF' for token classes. Operators that only continue the expression before an error are left out.
+
+/* FIRST sets */
+
+pub(crate) const FIRST_ATOM: &[&str] = &[
+    "NAME", "NUMBER", "STRING", "FSTRING_START", "TSTRING_START", "(", "[", "{", "...", "None", "True", "False"
+];
+
+pub(crate) const FIRST_FACTOR: &[&str] = &[
+    "NAME", "NUMBER", "STRING", "FSTRING_START", "TSTRING_START", "(", "[", "{", "...", "None", "True", "False",
+    "-", "+", "~", "await"
+];
+
+pub(crate) const FIRST_NOT_TEST: &[&str] = &[
+    "NAME", "NUMBER", "STRING", "FSTRING_START", "TSTRING_START", "(", "[", "{", "...", "None", "True", "False",
+    "-", "+", "~", "await", "not"
+];
+
+pub(crate) const FIRST_EXPRESSION: &[&str] = &[
+    "NAME", "NUMBER", "STRING", "FSTRING_START", "TSTRING_START", "(", "[", "{", "...", "None", "True", "False",
+    "-", "+", "~", "await", "not", "lambda"
+];
+
+pub(crate) const FIRST_STAR_EXPRESSION: &[&str] = &[
+    "NAME", "NUMBER", "STRING", "FSTRING_START", "TSTRING_START", "(", "[", "{", "...", "None", "True", "False",
+    "-", "+", "~", "await", "not", "lambda", "*"
+];
+
+pub(crate) const FIRST_EXPRESSION_STMT: &[&str] = &[
+    "NAME", "NUMBER", "STRING", "FSTRING_START", "TSTRING_START", "(", "[", "{", "...", "None", "True", "False",
+    "-", "+", "~", "await", "not", "lambda", "*", "yield"
+];
+
+pub(crate) const FIRST_PATTERN: &[&str] = &[
+    "NAME", "NUMBER", "STRING", "-", "(", "[", "{", "*", "None", "True", "False"
+];
+
+pub(crate) const FIRST_MAPPING_KEY: &[&str] = &[
+    "NAME", "NUMBER", "STRING", "-", "None", "True", "False"
+];
+
+/* FOLLOW sets */
+
+pub(crate) const FOLLOW_SMALL_STMT: &[&str] = &[";", "NEWLINE"];
+
+pub(crate) const FOLLOW_EVAL_INPUT: &[&str] = &["NEWLINE", "EOF"];
+
+pub(crate) const FOLLOW_FROM: &[&str] = &["NAME", ".", "..."];
+
+pub(crate) const FOLLOW_TRY_BODY: &[&str] = &["except", "finally"];
+
+pub(crate) const FOLLOW_ASYNC: &[&str] = &["def", "with", "for"];
+
+pub(crate) const FOLLOW_DECORATORS: &[&str] = &["@", "def", "class", "async"];
+
+pub(crate) const FOLLOW_DECORATOR_NAME: &[&str] = &[".", "(", "NEWLINE"];
+
+pub(crate) const FOLLOW_CLASS_NAME: &[&str] = &["[", "(", ":"];
+
+pub(crate) const FOLLOW_ARGUMENT: &[&str] = &[")", ","];
+
+pub(crate) const FOLLOW_SUBSCRIPT: &[&str] = &["]", ",", ":"];
+
+pub(crate) const FOLLOW_TUPLE_ELEMENT: &[&str] = &[")", ",", "for", "async"];
+
+pub(crate) const FOLLOW_LIST_ELEMENT: &[&str] = &["]", ",", "for", "async"];
+
+pub(crate) const FOLLOW_MAPPING_ELEMENT: &[&str] = &["}", ","];
+
+pub(crate) const FOLLOW_MATCH_SUBJECT: &[&str] = &[",", ":"];
+
+pub(crate) const FOLLOW_CASE_BLOCK: &[&str] = &["case", "DEDENT"];
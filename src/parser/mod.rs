@@ -7,4 +7,8 @@ pub(crate) mod python_core_statement_parser;
 pub(crate) mod python_core_match_parser;
 pub(crate) mod python_core_block_parser;
 pub(crate) mod python_core_tokenizer;
-pub(crate) mod syntax_error;
+pub(crate) mod diagnostic;
+pub(crate) mod expected_tokens;
+pub(crate) mod python_version;
+pub(crate) mod source_decoder;
+pub(crate) mod parser_options;
@@ -0,0 +1,42 @@
+use super::python_version::{Feature, PythonVersion};
+
+/// Grammar rule parsing starts from, as the 'mode' argument of 'compile()'.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StartRule {
+    File,
+    Eval,
+    Single,
+    FuncType,
+}
+
+/// Settings for one parse.
+#[derive(Clone, Debug)]
+pub struct ParserOptions {
+    /// Version the source must run on, newer features are reported.
+    pub target: PythonVersion,
+    pub start: StartRule,
+    /// Collect '# type:' comments as TYPE_COMMENT tokens, as CPython's 'type_comments=True'.
+    pub type_comments: bool,
+    /// Features turned on or off regardless of the target, the last entry for a feature wins.
+    pub features: Vec<(Feature, bool)>,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        ParserOptions {
+            target: PythonVersion::LATEST,
+            start: StartRule::File,
+            type_comments: false,
+            features: Vec::new(),
+        }
+    }
+}
+
+impl ParserOptions {
+    pub fn is_enabled(&self, feature: Feature) -> bool {
+        match self.features.iter().rev().find(|(f, _ )| *f == feature) {
+            Some(( _ , on)) => *on,
+            None => self.target >= feature.version()
+        }
+    }
+}
@@ -2,35 +2,39 @@
 use crate::parser::python_core_expression_parser::ExpressionRules;
 use crate::parser::python_core_statement_parser::StatementRules;
 use crate::parser::python_core_tokenizer::LexerMethods;
-use crate::parser::syntax_error::SyntaxError;
-use crate::parser::syntax_nodes::SyntaxNode;
+use crate::parser::python_version::Feature;
+use crate::parser::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::parser::expected_tokens::{FOLLOW_CLASS_NAME, FOLLOW_DECORATORS, FOLLOW_DECORATOR_NAME, FOLLOW_EVAL_INPUT};
+use crate::parser::syntax_nodes::{ParameterKind, SyntaxNode};
 use crate::parser::token_nodes::Token;
 use super::python_core_parser::PythonCoreParser;
 
 pub trait BlockGrammarRules {
-    fn parse_single_input(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_file_input(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_eval_input(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_decorator_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_decorators_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_decorated_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_class_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_func_def_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_parameters_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_typed_args_list_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_tfp_def(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_var_args_list_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_argument_element(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_star_argument_element(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_power_argument_element(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_func_body_suite_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_func_type_input(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_func_type(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_type_list(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
+    fn parse_single_input(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_file_input(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_eval_input(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_decorator_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_decorators_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_decorated_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_class_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_func_def_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_type_params(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_type_param(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_parameters_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_typed_args_list_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_tfp_def(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_var_args_list_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_vfp_def(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_parameter_list(&mut self, is_lambda: bool) -> Result<Vec<Box<SyntaxNode>>, Box<Diagnostic>>;
+    fn parse_func_body_suite_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_func_type_input(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_func_type_comment(&mut self, symbol: &Token) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_func_type(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_type_list(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
 }
 
 impl BlockGrammarRules for PythonCoreParser {
-    fn parse_single_input(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_single_input(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         match &*self.lexer.symbol {
             Token::NewlineToken( _ , _ , _ , _ , _ ) => {
@@ -55,7 +59,7 @@ impl BlockGrammarRules for PythonCoreParser {
                         self.lexer.advance();
                         Ok(Box::new(SyntaxNode::SingleInputStmtNode(pos, self.lexer.position, Some(right), Some(symbol2))))
                     },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NEWLINE after statement in single input!"))))
+                    _ => Err(self.error_expecting(DiagnosticCode::ExpectedNewline, "Expecting NEWLINE after statement in single input!", &["NEWLINE"]))
                 }
             },
             _ => {
@@ -65,7 +69,7 @@ impl BlockGrammarRules for PythonCoreParser {
         }
     }
 
-    fn parse_file_input(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_file_input(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -73,9 +77,6 @@ impl BlockGrammarRules for PythonCoreParser {
         loop {
             match &*self.lexer.symbol {
                 Token::EofToken( _ , _ , _ ) => {
-                    nodes.reverse();
-                    separators.reverse();
-
                     return Ok(Box::new(SyntaxNode::FileInputStmtNode(pos, self.lexer.position, nodes, separators, self.lexer.symbol.clone())))
                 },
                 Token::NewlineToken( _ , _ , _ , _ , _ ) => {
@@ -83,12 +84,12 @@ impl BlockGrammarRules for PythonCoreParser {
                     self.lexer.advance();
                     separators.push(symbol)
                 },
-                _ => nodes.push(self.parse_stmt()?),
+                _ => nodes.push(self.parse_stmt_or_recover()),
             }
         }
     }
 
-    fn parse_eval_input(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_eval_input(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let right = self.parse_test_list_expr()?;
         let mut nodes = Vec::<Box<Token>>::new();
@@ -101,12 +102,12 @@ impl BlockGrammarRules for PythonCoreParser {
                     nodes.push(symbol1)
                 },
                 Token::EofToken( _ , _ , _ ) => return Ok(Box::new(SyntaxNode::EvalInputStmtNode(pos, self.lexer.position, right, nodes, self.lexer.symbol.clone()))),
-                _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting End of file at end of eval input!"))))
+                _ => return Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting End of file at end of eval input!", FOLLOW_EVAL_INPUT))
             }
         }
     }
 
-    fn parse_decorator_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_decorator_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol1 = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -120,22 +121,16 @@ impl BlockGrammarRules for PythonCoreParser {
                     Token::RightParenToken( _ , _ , _ ) => None,
                     _ => Some(self.parse_arg_list_expr()?)
                 };
+                let symbol3 = Some(self.expect_token(Token::RightParenToken, "Expecting ')' after decorator argument name!"));
+
                 match &*self.lexer.symbol {
-                    Token::RightParenToken( _ , _ , _ ) => {
-                        let symbol3 = Some(self.lexer.symbol.clone());
+                    Token::NewlineToken( _ , _ , _ , _ , _ ) => {
+                        let symbol4 = self.lexer.symbol.clone();
                         self.lexer.advance();
 
-                        match &*self.lexer.symbol {
-                            Token::NewlineToken( _ , _ , _ , _ , _ ) => {
-                                let symbol4 = self.lexer.symbol.clone();
-                                self.lexer.advance();
-
-                                Ok(Box::new(SyntaxNode::DecoratorStmtNode(pos, self.lexer.position, symbol1, left, symbol2, right, symbol3, symbol4)))
-                            },
-                            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NEWLINE after decorator argument name!"))))
-                        }
+                        Ok(Box::new(SyntaxNode::DecoratorStmtNode(pos, self.lexer.position, symbol1, left, symbol2, right, symbol3, symbol4)))
                     },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ')' after decorator argument name!"))))
+                    _ => Err(self.error_expecting(DiagnosticCode::ExpectedNewline, "Expecting NEWLINE after decorator argument name!", &["NEWLINE"]))
                 }
             },
             Token::NewlineToken( _ , _ , _ , _ , _ ) => {
@@ -143,11 +138,11 @@ impl BlockGrammarRules for PythonCoreParser {
                 self.lexer.advance();
                 Ok(Box::new(SyntaxNode::DecoratorStmtNode(pos, self.lexer.position, symbol1, left, None, None, None, symbol4)))
             }
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting '(' or NEWLINE after decorator name!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedNewline, "Expecting '(' or NEWLINE after decorator name!", FOLLOW_DECORATOR_NAME))
         }
     }
 
-    fn parse_decorators_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_decorators_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         nodes.push(self.parse_decorator_stmt()?);
@@ -159,24 +154,23 @@ impl BlockGrammarRules for PythonCoreParser {
             }
         }
 
-        nodes.reverse();
         Ok(Box::new(SyntaxNode::DecoratorsStmtNode(pos, self.lexer.position, nodes)))
     }
 
-    fn parse_decorated_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_decorated_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let left = self.parse_decorators_stmt()?;
         let right = match &*self.lexer.symbol {
             Token::ClassToken( _ , _ , _ ) => self.parse_class_stmt()?,
             Token::AsyncToken( _ , _ , _ ) => self.parse_async_stmt()?,
             Token::DefToken( _ , _ , _ ) => self.parse_func_def_stmt()?,
-            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'class', 'def' or 'async' after decorators!"))))
+            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting 'class', 'def' or 'async' after decorators!", FOLLOW_DECORATORS))
         };
 
         Ok(Box::new(SyntaxNode::DecoratedStmtNode(pos, self.lexer.position, left, right)))
     }
 
-    fn parse_class_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_class_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol1 = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -186,6 +180,11 @@ impl BlockGrammarRules for PythonCoreParser {
                 let symbol2 = self.lexer.symbol.clone();
                 self.lexer.advance();
 
+                let type_params = match &*self.lexer.symbol {
+                    Token::LeftSquareBracketToken( _ , _ , _ ) => Some(self.parse_type_params()?),
+                    _ => None
+                };
+
                 match &*self.lexer.symbol {
                     Token::LeftParenToken( _ , _ , _ ) => {
                         let symbol3 = Some(self.lexer.symbol.clone());
@@ -195,25 +194,13 @@ impl BlockGrammarRules for PythonCoreParser {
                             Token::RightParenToken( _ , _ , _ ) => None,
                             _ => Some(self.parse_arg_list_expr()?)
                         };
-                        match &*self.lexer.symbol {
-                            Token::RightParenToken( _ , _ , _ ) => {
-                                let symbol4 = Some(self.lexer.symbol.clone());
-                                self.lexer.advance();
+                        let symbol4 = Some(self.expect_token(Token::RightParenToken, "Expecting ')' in 'class' statement!"));
 
-                                match &*self.lexer.symbol {
-                                    Token::ColonToken( _ , _ , _ ) => {
-                                        let symbol5 = self.lexer.symbol.clone();
-                                        self.lexer.advance();
+                        let symbol5 = self.expect_token(Token::ColonToken, "Expecting ':' in 'class' statement!");
 
-                                        let next = self.parse_suite_stmt()?;
+                        let next = self.parse_suite_stmt()?;
 
-                                        Ok(Box::new(SyntaxNode::ClassDefStmtNode(pos, self.lexer.position, symbol1, symbol2, symbol3, right, symbol4, symbol5, next)))
-                                    },
-                                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'class' statement!"))))
-                                }
-                            },
-                            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ')' in 'class' statement!"))))
-                        }
+                        Ok(Box::new(SyntaxNode::ClassDefStmtNode(pos, self.lexer.position, symbol1, symbol2, type_params, symbol3, right, symbol4, symbol5, next)))
                     },
                     Token::ColonToken( _ , _ , _ ) => {
                         let symbol3 = self.lexer.symbol.clone();
@@ -221,17 +208,17 @@ impl BlockGrammarRules for PythonCoreParser {
 
                         let right = self.parse_suite_stmt()?;
 
-                        Ok(Box::new(SyntaxNode::ClassDefStmtNode(pos, self.lexer.position, symbol1, symbol2, None, None, None, symbol3, right)))
+                        Ok(Box::new(SyntaxNode::ClassDefStmtNode(pos, self.lexer.position, symbol1, symbol2, type_params, None, None, None, symbol3, right)))
                     },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'class' statement!"))))
+                    _ => Err(self.error_expecting(DiagnosticCode::ExpectedColon, "Expecting ':' in 'class' statement!", FOLLOW_CLASS_NAME))
                 }
 
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME after 'class'!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME after 'class'!", &["NAME"]))
         }
     }
 
-    fn parse_func_def_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_func_def_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol1 = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -241,6 +228,11 @@ impl BlockGrammarRules for PythonCoreParser {
                 let name = self.lexer.symbol.clone();
                 self.lexer.advance();
 
+                let type_params = match &*self.lexer.symbol {
+                    Token::LeftSquareBracketToken( _ , _ , _ ) => Some(self.parse_type_params()?),
+                    _ => None
+                };
+
                 let left = self.parse_parameters_stmt()?;
 
                 let (symbol2, node1 ) = match &*self.lexer.symbol {
@@ -255,69 +247,181 @@ impl BlockGrammarRules for PythonCoreParser {
                     _ => ( None, None )
                 };
 
-                match &*self.lexer.symbol {
-                    Token::ColonToken( _ , _ , _ ) => {
+                let symbol3 = self.expect_token(Token::ColonToken, "Expecting ':' in 'def' statement!");
+
+                let tc = match &*self.lexer.symbol {
+                    Token::TypeCommentToken( _ , _ , _ , _ ) => {
                         let symbol3 = self.lexer.symbol.clone();
                         self.lexer.advance();
 
-                        let tc = match &*self.lexer.symbol {
-                            Token::TypeCommentToken( _ , _ , _ , _ ) => {
-                                let symbol3 = self.lexer.symbol.clone();
-                                self.lexer.advance();
-
-                                Some(symbol3)
-                            },
-                            _ => None
-                        };
-
-                        let next = self.parse_func_body_suite_stmt()?;
+                        Some(symbol3)
+                    },
+                    _ => None
+                };
 
-                        Ok(Box::new(SyntaxNode::FuncDefinitionNode(pos, self.lexer.position, symbol1, name, left, symbol2, node1, symbol3,  tc, next)))
+                let next = self.parse_func_body_suite_stmt()?;
+
+                /* Signature from a type comment after ':' or on the first line of the body, a malformed one is reported and left out */
+                let func_type = match (&tc, &*next) {
+                    (Some(symbol4), _ ) |
+                    (None, SyntaxNode::FuncBodyStmtNode( _ , _ , _ , Some(symbol4), _ , _ , _ , _ )) => {
+                        match self.parse_func_type_comment(symbol4) {
+                            Ok(node) => Some(node),
+                            Err(error) => {
+                                self.report(error);
+                                None
+                            }
+                        }
                     },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'def' statement!"))))
-                }
+                    _ => None
+                };
+
+                Ok(Box::new(SyntaxNode::FuncDefinitionNode(pos, self.lexer.position, symbol1, name, type_params, left, symbol2, node1, symbol3,  tc, next, func_type)))
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME after 'def'!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME after 'def'!", &["NAME"]))
         }
     }
 
-    fn parse_parameters_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_type_params(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
-        match &*self.lexer.symbol {
-            Token::LeftParenToken( _ , _ , _ ) => {
-                let symbol1 = self.lexer.symbol.clone();
+        let mut nodes = Vec::<Box<SyntaxNode>>::new();
+        let mut separators = Vec::<Box<Token>>::new();
+        let mut has_default = false;
+
+        self.lexer.require_feature(Feature::TypeParameters, pos);
+        let symbol1 = self.lexer.symbol.clone();
+        self.lexer.advance();
+
+        let mut is_last = matches!(&*self.lexer.symbol, Token::RightSquareBracketToken( _ , _ , _ ));
+        if is_last {
+            let error = self.error(DiagnosticCode::InvalidParameters, "Type parameter list cannot be empty");
+            self.report(error)
+        }
+
+        while !is_last {
+            let node = self.parse_type_param()?;
+
+            match &*node {
+                SyntaxNode::TypeParamNode( _ , _ , _ , _ , _ , _ , Some( _ ) , _ ) => has_default = true,
+                SyntaxNode::TypeParamNode( _ , _ , _ , name , _ , _ , None , _ ) if has_default => {
+                    let (start, end) = name.span();
+                    self.report(Box::new(Diagnostic::error(DiagnosticCode::InvalidParameters, start, end, format!("non-default type parameter '{}' follows default type parameter", name.text()))))
+                },
+                _ => ()
+            }
+            nodes.push(node);
+
+            match &*self.lexer.symbol {
+                Token::CommaToken( _ , _ , _ ) => {
+                    separators.push(self.lexer.symbol.clone());
+                    self.lexer.advance();
+
+                    is_last = matches!(&*self.lexer.symbol, Token::RightSquareBracketToken( _ , _ , _ ))
+                },
+                _ => is_last = true
+            }
+        }
+
+        let symbol2 = self.expect_token(Token::RightSquareBracketToken, "Expecting ']' in type parameter list!");
+
+        Ok(Box::new(SyntaxNode::TypeParamsNode(pos, self.lexer.position, symbol1, nodes, separators, symbol2)))
+    }
+
+    /// Type variable 'T', with an optional bound or constraints and a default, TypeVarTuple '*Ts' or ParamSpec '**P'.
+    fn parse_type_param(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+        let pos = self.lexer.position;
+
+        let symbol1 = match &*self.lexer.symbol {
+            Token::MultiplyToken( _ , _ , _ ) |
+            Token::PowerToken( _ , _ , _ ) => {
+                let symbol = self.lexer.symbol.clone();
                 self.lexer.advance();
+                Some(symbol)
+            },
+            _ => None
+        };
 
-                let right = match &*self.lexer.symbol {
-                    Token::RightParenToken( _ , _ , _ ) => None,
-                    _ => Some(self.parse_typed_args_list_stmt()?)
-                };
+        let name = match &*self.lexer.symbol {
+            Token::NameToken( _ , _ , _ , _ ) => {
+                let symbol = self.lexer.symbol.clone();
+                self.lexer.advance();
+                symbol
+            },
+            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME in type parameter!", &["NAME"]))
+        };
 
-                match &*self.lexer.symbol {
-                    Token::RightParenToken( _ , _ , _ ) => {
-                        let symbol2 = self.lexer.symbol.clone();
-                        self.lexer.advance();
+        let (symbol2, bound) = match &*self.lexer.symbol {
+            Token::ColonToken( _ , _ , _ ) => {
+                let symbol = self.lexer.symbol.clone();
+                self.lexer.advance();
 
-                        Ok(Box::new(SyntaxNode::ParametersNode(pos, self.lexer.position, symbol1, right, symbol2)))
-                    },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ')' in function declaration!"))))
+                let right = self.parse_test_expr()?;
+
+                if let Some(star) = &symbol1 {
+                    let kind = match &*right {
+                        SyntaxNode::TupleExprNode( .. ) => "constraints",
+                        _ => "bound"
+                    };
+                    let target = match &**star {
+                        Token::MultiplyToken( _ , _ , _ ) => "TypeVarTuple",
+                        _ => "ParamSpec"
+                    };
+                    self.report(Box::new(Diagnostic::error(DiagnosticCode::InvalidParameters, symbol.span().0, self.lexer.position, format!("cannot use {} with {}", kind, target))))
                 }
 
+                (Some(symbol), Some(right))
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting '(' in function declaration!"))))
-        }
+            _ => (None, None)
+        };
+
+        let (symbol3, default) = match &*self.lexer.symbol {
+            Token::AssignToken( _ , _ , _ ) => {
+                self.lexer.require_feature(Feature::TypeParameterDefaults, self.lexer.position);
+                let symbol = self.lexer.symbol.clone();
+                self.lexer.advance();
+
+                /* A TypeVarTuple default may be unpacked, as in '*Ts = *tuple[int]' */
+                let right = match (&symbol1, &*self.lexer.symbol) {
+                    (Some(star), Token::MultiplyToken( _ , _ , _ )) if matches!(&**star, Token::MultiplyToken( _ , _ , _ )) => self.parse_star_expr()?,
+                    _ => self.parse_test_expr()?
+                };
+
+                (Some(symbol), Some(right))
+            },
+            _ => (None, None)
+        };
+
+        Ok(Box::new(SyntaxNode::TypeParamNode(pos, self.lexer.position, symbol1, name, symbol2, bound, symbol3, default)))
     }
 
-    fn parse_typed_args_list_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
-        todo!()
+    fn parse_parameters_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+        let pos = self.lexer.position;
+        let symbol1 = self.expect_token(Token::LeftParenToken, "Expecting '(' in function declaration!");
+
+        let right = match &*self.lexer.symbol {
+            Token::RightParenToken( _ , _ , _ ) => None,
+            _ => Some(self.parse_typed_args_list_stmt()?)
+        };
+
+        let symbol2 = self.expect_token(Token::RightParenToken, "Expecting ')' in function declaration!");
+
+        Ok(Box::new(SyntaxNode::ParametersNode(pos, self.lexer.position, symbol1, right, symbol2)))
+
     }
 
-    fn parse_tfp_def(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_typed_args_list_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+        let pos = self.lexer.position;
+        let nodes = self.parse_parameter_list(false)?;
+
+        Ok(Box::new(SyntaxNode::TypedListNode(pos, self.lexer.position, nodes)))
+    }
+
+    fn parse_tfp_def(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
 
         match &*self.lexer.symbol {
             Token::NameToken( _ , _ , _ , _ ) => {
-                let left = self.parse_test_expr()?;
+                let left = self.parse_atom_expr()?;
 
                 match &*self.lexer.symbol {
                     Token::ColonToken( _ , _ , _ ) => {
@@ -331,187 +435,195 @@ impl BlockGrammarRules for PythonCoreParser {
                     _ => Ok(left)
                 }
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting Name literal in argument!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting Name literal in argument!", &["NAME"]))
         }
     }
 
-    fn parse_var_args_list_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_var_args_list_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
-        let mut nodes = Vec::<Box<SyntaxNode>>::new();
-        let mut separators = Vec::<Box<Token>>::new();
-        match &*self.lexer.symbol {
-            Token::PowerToken( _ , _ , _ ) => {
-                nodes.push(self.parse_power_argument_element()?);
-                Ok(Box::new(SyntaxNode::VarListNode(pos, self.lexer.position, nodes, separators, None)))
-            },
-            Token::MultiplyToken( _ , _ , _ ) => {
-                nodes.push(self.parse_star_argument_element()?);
+        let nodes = self.parse_parameter_list(true)?;
 
-                loop {
-                    match &*self.lexer.symbol {
-                        Token::CommaToken( _ , _ , _ ) => {
-                            separators.push(self.lexer.symbol.clone());
-                            self.lexer.advance();
+        Ok(Box::new(SyntaxNode::VarListNode(pos, self.lexer.position, nodes)))
+    }
 
-                            match &*self.lexer.symbol {
-                                Token::ColonToken( _ , _ , _ ) => break,
-                                Token::PowerToken( _ , _ , _ ) => {
-                                    nodes.push(self.parse_power_argument_element()?);
-
-                                    match &*self.lexer.symbol {
-                                        Token::CommaToken( _ , _ , _ ) => {
-                                            separators.push(self.lexer.symbol.clone());
-                                            self.lexer.advance()
-                                        },
-                                        _ => ()
-                                    }
+    fn parse_vfp_def(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+        match &*self.lexer.symbol {
+            Token::NameToken( _ , _ , _ , _ ) => self.parse_atom_expr(),
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME in argument!", &["NAME"]))
+        }
+    }
 
-                                    break
-                                },
-                                _ => nodes.push(self.parse_argument_element()?)
-                            }
+    /// Parameters of a 'def' up to ')', or of a 'lambda' up to ':' without annotations and type comments.
+    /// Parameters before '/' are marked positional-only once the '/' is found. Parameters in the wrong
+    /// order or with a default they cannot have are reported, and kept in the list.
+    fn parse_parameter_list(&mut self, is_lambda: bool) -> Result<Vec<Box<SyntaxNode>>, Box<Diagnostic>> {
+        let pos = self.lexer.position;
+        let mut nodes = Vec::<Box<SyntaxNode>>::new();
+        let mut has_default = false;
+        let mut has_slash = false;
+        let mut star : Option<bool> = None; /* Seen '*', and whether a named parameter followed a bare '*' */
+
+        loop {
+            let pos2 = self.lexer.position;
+
+            let (left, kind) = match &*self.lexer.symbol {
+                Token::DivideToken( _ , _ , _ ) => {
+                    let message = match (has_slash, star.is_some(), nodes.is_empty()) {
+                        (true, _ , _ ) => Some("/ may appear only once"),
+                        ( _ , true, _ ) => Some("/ must be ahead of *"),
+                        ( _ , _ , true) => Some("at least one argument must precede /"),
+                        _ => None
+                    };
+                    match message {
+                        Some(message) => {
+                            let error = self.error(DiagnosticCode::InvalidParameters, message);
+                            self.report(error)
                         },
-                        _ => break
+                        None => {
+                            has_slash = true;
+                            for node in nodes.iter_mut() {
+                                if let SyntaxNode::ParameterNode( _ , _ , _ , _ , _ , kind ) = &mut **node {
+                                    *kind = ParameterKind::PositionalOnly
+                                }
+                            }
+                        }
                     }
-                }
 
-                nodes.reverse();
-                separators.reverse();
-                Ok(Box::new(SyntaxNode::VarListNode(pos, self.lexer.position, nodes, separators, None)))
-            },
-            _ => {
-                let mut slash : Option<Box<Token>> = None;
-                nodes.push(self.parse_argument_element()?);
+                    let symbol1 = self.lexer.symbol.clone();
+                    self.lexer.advance();
 
-                loop {
-                    match &*self.lexer.symbol {
-                        Token::CommaToken( _ , _ , _ ) => {
-                            separators.push(self.lexer.symbol.clone());
-                            self.lexer.advance();
+                    (Box::new(SyntaxNode::SlashElementNode(pos2, self.lexer.position, symbol1)), ParameterKind::PositionalOnlySeparator)
+                },
+                Token::MultiplyToken( _ , _ , _ ) => {
+                    let has_star = star.is_some();
+                    if has_star {
+                        let error = self.error(DiagnosticCode::InvalidParameters, "* argument may appear only once");
+                        self.report(error)
+                    }
+                    let symbol1 = self.lexer.symbol.clone();
+                    self.lexer.advance();
 
-                            match &*self.lexer.symbol {
-                                Token::DivideToken( _ , _ , _ ) => {
-                                    match &slash {
-                                        None => {
-                                            slash = Some(self.lexer.symbol.clone());
-                                            self.lexer.advance();
-                                        },
-                                        _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Already found '/' in argument list!"))))
-                                    }
-                                },
-                                Token::MultiplyToken( _ , _ , _ ) => {
-                                    nodes.push(self.parse_star_argument_element()?);
+                    let right = match (&*self.lexer.symbol, is_lambda) {
+                        (Token::NameToken( _ , _ , _ , _ ), true) => Some(self.parse_vfp_def()?),
+                        (Token::NameToken( _ , _ , _ , _ ), _ ) => Some(self.parse_tfp_def()?),
+                        _ => None
+                    };
+                    if !has_star {
+                        star = Some(right.is_some())
+                    }
 
-                                    loop {
-                                        match &*self.lexer.symbol {
-                                            Token::CommaToken( _ , _ , _ ) => {
-                                                separators.push(self.lexer.symbol.clone());
-                                                self.lexer.advance();
+                    let kind = match right.is_some() {
+                        true => ParameterKind::VarPositional,
+                        _ => ParameterKind::KeywordOnlySeparator
+                    };
 
-                                                match &*self.lexer.symbol {
-                                                    Token::ColonToken( _ , _ , _ ) => break,
-                                                    Token::PowerToken( _ , _ , _ ) => {
-                                                        nodes.push(self.parse_power_argument_element()?);
+                    (Box::new(SyntaxNode::VarStarElementNode(pos2, self.lexer.position, symbol1, right)), kind)
+                },
+                Token::PowerToken( _ , _ , _ ) => {
+                    let symbol1 = self.lexer.symbol.clone();
+                    self.lexer.advance();
 
-                                                        match &*self.lexer.symbol {
-                                                            Token::CommaToken( _ , _ , _ ) => {
-                                                                separators.push(self.lexer.symbol.clone());
-                                                                self.lexer.advance()
-                                                            },
-                                                            _ => ()
-                                                        }
+                    let right = match is_lambda {
+                        true => self.parse_vfp_def()?,
+                        _ => self.parse_tfp_def()?
+                    };
 
-                                                        break
-                                                    },
-                                                    _ => nodes.push(self.parse_argument_element()?)
-                                                }
-                                            },
-                                            _ => break
-                                        }
-                                    }
+                    (Box::new(SyntaxNode::VarPowerElementNode(pos2, self.lexer.position, symbol1, right)), ParameterKind::VarKeyword)
+                },
+                _ => {
+                    let left = match is_lambda {
+                        true => self.parse_vfp_def()?,
+                        _ => self.parse_tfp_def()?
+                    };
+                    let kind = match star {
+                        Some( _ ) => ParameterKind::KeywordOnly,
+                        None => ParameterKind::PositionalOrKeyword
+                    };
+                    if star == Some(false) {
+                        star = Some(true)
+                    }
 
-                                    break
-                                },
-                                Token::PowerToken( _ , _ , _ ) => {
-                                    nodes.push(self.parse_power_argument_element()?);
-                                    match &*self.lexer.symbol {
-                                        Token::CommaToken( _ , _ , _ ) => {
-                                            separators.push(self.lexer.symbol.clone());
-                                            self.lexer.advance()
-                                        },
-                                        _ => ()
-                                    }
-                                    break
-                                },
-                                _ => nodes.push(self.parse_argument_element()?)
-                            }
+                    match &*self.lexer.symbol {
+                        Token::AssignToken( _ , _ , _ ) => {
+                            let symbol1 = self.lexer.symbol.clone();
+                            self.lexer.advance();
+
+                            let right = self.parse_test_expr()?;
+                            has_default = true;
+
+                            (Box::new(SyntaxNode::VarElementNode(pos2, self.lexer.position, left, symbol1, right)), kind)
                         },
-                        _ => break
+                        _ if has_default && star.is_none() => {
+                            self.report(Box::new(Diagnostic::error(DiagnosticCode::InvalidParameters, pos2, self.lexer.position, String::from("parameter without a default follows parameter with a default"))));
+                            (left, kind)
+                        },
+                        _ => (left, kind)
                     }
                 }
+            };
+
+            /* A default for '*', '**' or '/' is reported and kept with it */
+            let left = match &*self.lexer.symbol {
+                Token::AssignToken( _ , _ , _ ) => {
+                    let error = self.error(DiagnosticCode::InvalidParameters, match kind {
+                        ParameterKind::VarKeyword => "var-keyword argument cannot have default value",
+                        ParameterKind::PositionalOnlySeparator => "/ cannot have default value",
+                        _ => "var-positional argument cannot have default value"
+                    });
+                    self.report(error);
 
-                nodes.reverse();
-                separators.reverse();
-                Ok(Box::new(SyntaxNode::VarListNode(pos, self.lexer.position, nodes, separators, slash)))
-            }
-        }
-    }
-
-    fn parse_argument_element(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
-        let pos = self.lexer.position;
-
-        let left = match &*self.lexer.symbol {
-            Token::NameToken( _ , _ , _ , _ ) => self.parse_atom_expr()?,
-            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME in argument!"))))
-        };
+                    let symbol1 = self.lexer.symbol.clone();
+                    self.lexer.advance();
+                    let right = self.parse_test_expr()?;
 
-        match &*self.lexer.symbol {
-            Token::AssignToken( _ , _ , _ ) => {
-                let symbol1 = self.lexer.symbol.clone();
-                self.lexer.advance();
+                    Box::new(SyntaxNode::VarElementNode(pos2, self.lexer.position, left, symbol1, right))
+                },
+                _ => left
+            };
 
-                let right = self.parse_test_expr()?;
+            /* Optional ',' and a type comment for the parameter, as in 'a,  # type: int' */
+            let symbol2 = match &*self.lexer.symbol {
+                Token::CommaToken( _ , _ , _ ) => {
+                    let symbol = self.lexer.symbol.clone();
+                    self.lexer.advance();
+                    Some(symbol)
+                },
+                _ => None
+            };
 
-                Ok(Box::new(SyntaxNode::VarElementNode(0, self.lexer.position, left, symbol1, right)))
-            },
-            _ => Ok(left)
+            let tc = match &*self.lexer.symbol {
+                Token::TypeCommentToken( _ , _ , _ , _ ) if !is_lambda => {
+                    let symbol = self.lexer.symbol.clone();
+                    self.lexer.advance();
+                    Some(symbol)
+                },
+                _ => None
+            };
+
+            let is_last = symbol2.is_none() || match &*self.lexer.symbol {
+                Token::RightParenToken( _ , _ , _ ) => !is_lambda,
+                Token::ColonToken( _ , _ , _ ) => is_lambda,
+                _ => false
+            };
+            nodes.push(Box::new(SyntaxNode::ParameterNode(pos2, self.lexer.position, left, symbol2, tc, kind)));
+
+            if is_last {
+                break
+            }
+            if kind == ParameterKind::VarKeyword {
+                let error = self.error(DiagnosticCode::InvalidParameters, "arguments cannot follow var-keyword argument");
+                self.report(error)
+            }
         }
-    }
-
-    fn parse_star_argument_element(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
-        let pos = self.lexer.position;
-        let symbol = self.lexer.symbol.clone();
-        self.lexer.advance();
-
-        match &*self.lexer.symbol {
-            Token::AssignToken( _ , _ , _ ) => {
-                let symbol1 = self.lexer.symbol.clone();
-                self.lexer.advance();
 
-                let right = self.parse_test_expr()?;
-
-                Ok(Box::new(SyntaxNode::VarStarElementNode(pos, self.lexer.position, symbol, Some(right))))
-            },
-            _ => Ok(Box::new(SyntaxNode::VarStarElementNode(pos, self.lexer.position, symbol, None)))
+        if star == Some(false) {
+            self.report(Box::new(Diagnostic::error(DiagnosticCode::InvalidParameters, pos, self.lexer.position, String::from("named arguments must follow bare *"))))
         }
-    }
-
-    fn parse_power_argument_element(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
-        let pos = self.lexer.position;
-        let symbol = self.lexer.symbol.clone();
-        self.lexer.advance();
-
-        match &*self.lexer.symbol {
-            Token::NameToken( _ , _ , _ , _ ) => {
-                let right = self.parse_test_expr()?;
 
-                Ok(Box::new(SyntaxNode::VarPowerElementNode(pos, self.lexer.position, symbol, right)))
-            },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME in '**' argument!"))))
-        }
+        Ok(nodes)
     }
 
-    fn parse_func_body_suite_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_func_body_suite_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut tc : Option<Box<Token>> = None;
@@ -533,7 +645,7 @@ impl BlockGrammarRules for PythonCoreParser {
                                 tc_nl = Some(self.lexer.symbol.clone());
                                 self.lexer.advance()
                             },
-                            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NEWLINE after type comment in func body!"))))
+                            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedNewline, "Expecting NEWLINE after type comment in func body!", &["NEWLINE"]))
                         }
                     },
                     _ => ()
@@ -545,30 +657,26 @@ impl BlockGrammarRules for PythonCoreParser {
                         let symbol2 = self.lexer.symbol.clone();
                         self.lexer.advance();
 
-                        nodes.push (self.parse_stmt()?);
-
                         loop {
                             match &*self.lexer.symbol {
                                 Token::DedentToken( _ , _ , _ ) => {
                                     let symbol3 = self.lexer.symbol.clone();
                                     self.lexer.advance();
 
-                                    nodes.reverse();
-
                                     return Ok(Box::new(SyntaxNode::FuncBodyStmtNode(pos, self.lexer.position, symbol1, tc, tc_nl, symbol2, nodes, symbol3)))
                                 },
-                                _ => nodes.push (self.parse_stmt()?)
+                                _ => nodes.push(self.parse_stmt_or_recover())
                             }
                         }
                     },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting Indent in func body!"))))
+                    _ => Err(self.error_expecting(DiagnosticCode::ExpectedIndent, "Expecting Indent in func body!", &["INDENT"]))
                 }
             },
             _ => self.parse_simple_stmt()
         }
     }
 
-    fn parse_func_type_input(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_func_type_input(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let right = self.parse_func_type()?;
 
@@ -577,56 +685,50 @@ impl BlockGrammarRules for PythonCoreParser {
         loop {
             match &*self.lexer.symbol {
                 Token::NewlineToken( _ , _ , _ , _ , _ ) => {
-                    nodes.push(self.lexer.symbol.clone())
-                },_ => break
+                    nodes.push(self.lexer.symbol.clone());
+                    self.lexer.advance()
+                },
+                _ => break
             }
         }
 
-        nodes.reverse();
-
         match &*self.lexer.symbol {
             Token::EofToken( _ , _ , _ ) => Ok(Box::new(SyntaxNode::FuncTypeInputStmtNode(pos, self.lexer.position, right, nodes, self.lexer.symbol.clone()))),
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting EOF in func type input!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting EOF in func type input!", FOLLOW_EVAL_INPUT))
         }
     }
 
-    fn parse_func_type(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_func_type_comment(&mut self, symbol: &Token) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+        let mut parser = PythonCoreParser::from_lexer(Box::new(self.lexer.type_comment_lexer(symbol)));
+        parser.lexer.advance();
+
+        /* Only the first error in the type comment is kept, as later ones mostly follow from it */
+        let right = parser.parse_func_type_input();
+        match (right, parser.lexer.errors.into_iter().next()) {
+            (Ok(node), None) => Ok(node),
+            ( _ , Some(error)) | (Err(error), None) => Err(error)
+        }
+    }
+
+    fn parse_func_type(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
-        match &*self.lexer.symbol {
-            Token::LeftParenToken( _ , _ , _ ) => {
-                let symbol1 = self.lexer.symbol.clone();
-                self.lexer.advance();
+        let symbol1 = self.expect_token(Token::LeftParenToken, "Expecting '(' in func type input!");
 
-                let left = match &*self.lexer.symbol {
-                    Token::RightParenToken( _ , _ , _ ) => None,
-                    _ => Some(self.parse_type_list()?)
-                };
+        let left = match &*self.lexer.symbol {
+            Token::RightParenToken( _ , _ , _ ) => None,
+            _ => Some(self.parse_type_list()?)
+        };
 
-                match &*self.lexer.symbol {
-                    Token::RightParenToken( _ , _ , _ ) => {
-                        let symbol2 = self.lexer.symbol.clone();
-                        self.lexer.advance();
+        let symbol2 = self.expect_token(Token::RightParenToken, "Expecting ')' in func type input!");
 
-                        match &*self.lexer.symbol {
-                            Token::ArrowToken( _ , _ , _ ) => {
-                                let symbol3 = self.lexer.symbol.clone();
-                                self.lexer.advance();
+        let symbol3 = self.expect_token(Token::ArrowToken, "Expecting '->' in func type input!");
 
-                                let right = self.parse_test_expr()?;
+        let right = self.parse_test_expr()?;
 
-                                Ok(Box::new(SyntaxNode::FuncTypeStmtNode(pos, self.lexer.position, symbol1, left, symbol2, symbol3, right)))
-                            },
-                            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting '->' in func type input!"))))
-                        }
-                    },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ')' in func type input!"))))
-                }
-            },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting '(' in func type input!"))))
-        }
+        Ok(Box::new(SyntaxNode::FuncTypeStmtNode(pos, self.lexer.position, symbol1, left, symbol2, symbol3, right)))
     }
 
-    fn parse_type_list(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_type_list(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -661,7 +763,7 @@ impl BlockGrammarRules for PythonCoreParser {
                                     symbol2 = Some(self.lexer.symbol.clone());
                                     self.lexer.advance();
 
-                                    nodes.push(self.parse_test_expr()?);
+                                    node2 = Some(self.parse_test_expr()?);
                                     break
                                 },
                                 _ => nodes.push(self.parse_test_expr()?)
@@ -724,9 +826,6 @@ impl BlockGrammarRules for PythonCoreParser {
             }
         }
 
-        nodes.reverse();
-        separators.reverse();
-
         Ok(Box::new(SyntaxNode::TypeListStmtNode(pos, self.lexer.position, nodes, separators, symbol1, node1, symbol2, node2)))
     }
 }
@@ -734,8 +833,413 @@ impl BlockGrammarRules for PythonCoreParser {
 // Unittests for expression grammar rules //////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use crate::parser::parser_options::ParserOptions;
+    use crate::parser::diagnostic::DiagnosticCode;
+    use crate::parser::python_core_block_parser::BlockGrammarRules;
+    use crate::parser::python_core_expression_parser::ExpressionRules;
+    use crate::parser::python_core_parser::PythonCoreParser;
+    use crate::parser::python_core_statement_parser::StatementRules;
+    use crate::parser::python_version::PythonVersion;
+    use crate::parser::syntax_nodes::{ParameterKind, SyntaxNode};
+    use crate::parser::token_nodes::Token;
+
     #[test]
     fn dummy_test() {
         assert_eq!(1 == 1, true);
     }
+
+    #[test]
+    fn parse_func_def_with_type_comment() {
+        let mut parser = PythonCoreParser::with_options("def f(a, b):  # type: (int, str) -> None\n    pass\n", &ParserOptions { type_comments: true, ..ParserOptions::default() });
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::FuncDefinitionNode( _ , _ , _ , _ , _ , _ , None , None , _ , Some(tc) , _ , Some(func_type) ) => {
+                assert!(matches!(&**tc, Token::TypeCommentToken( _ , _ , text , _ ) if &**text == "# type: (int, str) -> None"));
+                match &**func_type {
+                    SyntaxNode::FuncTypeInputStmtNode( _ , _ , right , _ , _ ) => {
+                        assert!(matches!(&**right, SyntaxNode::FuncTypeStmtNode( _ , _ , _ , Some(list) , _ , _ , _ ) if matches!(&**list, SyntaxNode::TypeListStmtNode( _ , _ , nodes , separators , None , None , None , None ) if nodes.len() == 2 && separators.len() == 1)))
+                    },
+                    _ => panic!("Expecting func type input!")
+                }
+            },
+            _ => panic!("Expecting function definition with type comment!")
+        }
+        assert!(parser.lexer.errors.is_empty())
+    }
+
+    #[test]
+    fn parse_func_def_with_type_comment_in_body() {
+        let mut parser = PythonCoreParser::with_options("def f(a):\n    # type: (int) -> int\n    return a\n", &ParserOptions { type_comments: true, ..ParserOptions::default() });
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::FuncDefinitionNode( _ , _ , _ , _ , _ , _ , _ , _ , _ , None , body , Some(func_type) ) => {
+                assert!(matches!(&**body, SyntaxNode::FuncBodyStmtNode( _ , _ , _ , Some( _ ) , Some( _ ) , _ , nodes , _ ) if nodes.len() == 1));
+                assert!(matches!(&**func_type, SyntaxNode::FuncTypeInputStmtNode( _ , _ , right , _ , _ ) if matches!(&**right, SyntaxNode::FuncTypeStmtNode( _ , _ , _ , Some( _ ) , _ , _ , _ ))))
+            },
+            _ => panic!("Expecting function definition with type comment!")
+        }
+    }
+
+    #[test]
+    fn parse_func_def_with_per_argument_type_comments() {
+        let mut parser = PythonCoreParser::with_options("def f(a,  # type: int\n      b,  # type: str\n      ):\n    # type: (...) -> None\n    pass\n", &ParserOptions { type_comments: true, ..ParserOptions::default() });
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::FuncDefinitionNode( _ , _ , _ , _ , _ , params , _ , _ , _ , _ , _ , Some( _ ) ) => {
+                match &**params {
+                    SyntaxNode::ParametersNode( _ , _ , _ , Some(list) , _ ) => {
+                        match &**list {
+                            SyntaxNode::TypedListNode( _ , _ , nodes ) => {
+                                assert_eq!(nodes.len(), 2);
+                                assert!(matches!(&*nodes[0], SyntaxNode::ParameterNode( _ , _ , _ , Some( _ ) , Some(tc) , _ ) if matches!(&**tc, Token::TypeCommentToken( _ , _ , text , _ ) if &**text == "# type: int")));
+                                assert!(matches!(&*nodes[1], SyntaxNode::ParameterNode( _ , _ , _ , Some( _ ) , Some(tc) , _ ) if matches!(&**tc, Token::TypeCommentToken( _ , _ , text , _ ) if &**text == "# type: str")))
+                            },
+                            _ => panic!("Expecting typed list!")
+                        }
+                    },
+                    _ => panic!("Expecting parameters!")
+                }
+            },
+            _ => panic!("Expecting function definition!")
+        }
+    }
+
+    #[test]
+    fn parse_typed_args_list() {
+        let mut parser = PythonCoreParser::new("def f(a, b: int = 1, *args, c, **kw): pass\n");
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::FuncDefinitionNode( _ , _ , _ , _ , _ , params , _ , _ , _ , _ , _ , None ) => {
+                match &**params {
+                    SyntaxNode::ParametersNode( _ , _ , _ , Some(list) , _ ) => {
+                        match &**list {
+                            SyntaxNode::TypedListNode( _ , _ , nodes ) => {
+                                assert_eq!(nodes.len(), 5);
+                                assert!(matches!(&*nodes[1], SyntaxNode::ParameterNode( _ , _ , node , Some( _ ) , None , _ ) if matches!(&**node, SyntaxNode::VarElementNode( _ , _ , left , _ , _ ) if matches!(&**left, SyntaxNode::TypedFormalParameterNode( .. )))));
+                                assert!(matches!(&*nodes[2], SyntaxNode::ParameterNode( _ , _ , node , _ , _ , _ ) if matches!(&**node, SyntaxNode::VarStarElementNode( _ , _ , _ , Some( _ ) ))));
+                                assert!(matches!(&*nodes[4], SyntaxNode::ParameterNode( _ , _ , node , None , None , _ ) if matches!(&**node, SyntaxNode::VarPowerElementNode( .. ))))
+                            },
+                            _ => panic!("Expecting typed list!")
+                        }
+                    },
+                    _ => panic!("Expecting parameters!")
+                }
+            },
+            _ => panic!("Expecting function definition!")
+        }
+    }
+
+    #[test]
+    fn parse_typed_args_list_with_positional_only() {
+        let mut parser = PythonCoreParser::new("def f(a, b=1, /, c=2, *, d, **kw,): pass\n");
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::FuncDefinitionNode( _ , _ , _ , _ , _ , params , _ , _ , _ , _ , _ , None ) => {
+                match &**params {
+                    SyntaxNode::ParametersNode( _ , _ , _ , Some(list) , _ ) => {
+                        match &**list {
+                            SyntaxNode::TypedListNode( _ , _ , nodes ) => {
+                                let kinds = nodes.iter().map(|node| match &**node {
+                                    SyntaxNode::ParameterNode( _ , _ , _ , _ , _ , kind ) => *kind,
+                                    _ => panic!("Expecting parameter!")
+                                }).collect::<Vec<ParameterKind>>();
+                                assert_eq!(kinds, vec![ParameterKind::PositionalOnly, ParameterKind::PositionalOnly, ParameterKind::PositionalOnlySeparator, ParameterKind::PositionalOrKeyword,
+                                    ParameterKind::KeywordOnlySeparator, ParameterKind::KeywordOnly, ParameterKind::VarKeyword]);
+                                assert!(matches!(&*nodes[2], SyntaxNode::ParameterNode(14, 17, node , Some( _ ) , None , _ ) if matches!(&**node, SyntaxNode::SlashElementNode(14, 15, _ ))));
+                                assert!(matches!(&*nodes[6], SyntaxNode::ParameterNode( _ , _ , _ , Some( _ ) , None , _ )))
+                            },
+                            _ => panic!("Expecting typed list!")
+                        }
+                    },
+                    _ => panic!("Expecting parameters!")
+                }
+            },
+            _ => panic!("Expecting function definition!")
+        }
+    }
+
+    #[test]
+    fn parse_lambda_parameters() {
+        let mut parser = PythonCoreParser::new("lambda a, /, b=1, *args, c, **kw: a\n");
+        let node = parser.parse_test_expr().unwrap();
+
+        match &*node {
+            SyntaxNode::LambdaExprNode( _ , _ , _ , Some(list) , _ , _ , _ ) => {
+                match &**list {
+                    SyntaxNode::VarListNode( _ , _ , nodes ) => {
+                        let kinds = nodes.iter().map(|node| match &**node {
+                            SyntaxNode::ParameterNode( _ , _ , _ , _ , _ , kind ) => *kind,
+                            _ => panic!("Expecting parameter!")
+                        }).collect::<Vec<ParameterKind>>();
+                        assert_eq!(kinds, vec![ParameterKind::PositionalOnly, ParameterKind::PositionalOnlySeparator, ParameterKind::PositionalOrKeyword,
+                            ParameterKind::VarPositional, ParameterKind::KeywordOnly, ParameterKind::VarKeyword]);
+                        assert!(matches!(&*nodes[3], SyntaxNode::ParameterNode( _ , _ , node , _ , _ , _ ) if matches!(&**node, SyntaxNode::VarStarElementNode( _ , _ , _ , Some(name) ) if matches!(&**name, SyntaxNode::NameExprNode( .. )))))
+                    },
+                    _ => panic!("Expecting var list!")
+                }
+            },
+            _ => panic!("Expecting lambda!")
+        }
+    }
+
+    #[test]
+    fn parse_func_and_class_with_type_params() {
+        let mut parser = PythonCoreParser::new("def f[T, *Ts, **P](x: T) -> T: pass\n");
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::FuncDefinitionNode( _ , _ , _ , _ , Some(params) , _ , _ , _ , _ , _ , _ , _ ) => {
+                match &**params {
+                    SyntaxNode::TypeParamsNode(5, 18, _ , nodes , separators , _ ) => {
+                        assert_eq!(separators.len(), 2);
+                        assert!(matches!(&*nodes[0], SyntaxNode::TypeParamNode( _ , _ , None , name , None , None , None , None ) if name.text() == "T"));
+                        assert!(matches!(&*nodes[1], SyntaxNode::TypeParamNode( _ , _ , Some(star) , _ , _ , _ , _ , _ ) if matches!(&**star, Token::MultiplyToken( .. ))));
+                        assert!(matches!(&*nodes[2], SyntaxNode::TypeParamNode( _ , _ , Some(star) , _ , _ , _ , _ , _ ) if matches!(&**star, Token::PowerToken( .. ))))
+                    },
+                    _ => panic!("Expecting type parameters!")
+                }
+            },
+            _ => panic!("Expecting function definition!")
+        }
+
+        let mut parser = PythonCoreParser::new("class Box[T: Hashable = int, U: (int, str) = str, *Ts = *tuple[int]](Base): pass\n");
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::ClassDefStmtNode( _ , _ , _ , _ , Some(params) , Some( _ ) , Some( _ ) , _ , _ , _ ) => {
+                match &**params {
+                    SyntaxNode::TypeParamsNode( _ , _ , _ , nodes , _ , _ ) => {
+                        assert!(matches!(&*nodes[0], SyntaxNode::TypeParamNode( _ , _ , None , _ , Some( _ ) , Some(bound) , Some( _ ) , Some( _ ) ) if matches!(&**bound, SyntaxNode::NameExprNode( .. ))));
+                        assert!(matches!(&*nodes[1], SyntaxNode::TypeParamNode( _ , _ , None , _ , Some( _ ) , Some(bound) , _ , _ ) if matches!(&**bound, SyntaxNode::TupleExprNode( .. ))));
+                        assert!(matches!(&*nodes[2], SyntaxNode::TypeParamNode( _ , _ , Some( _ ) , _ , None , None , Some( _ ) , Some(default) ) if matches!(&**default, SyntaxNode::StarExprNode( .. ))))
+                    },
+                    _ => panic!("Expecting type parameters!")
+                }
+            },
+            _ => panic!("Expecting class definition!")
+        }
+        assert!(parser.lexer.errors.is_empty());
+    }
+
+    #[test]
+    fn parse_type_params_errors() {
+        let cases = [
+            ("def f[](): pass\n", "Type parameter list cannot be empty"),
+            ("def f[*Ts: int](): pass\n", "cannot use bound with TypeVarTuple"),
+            ("class A[**P: (int, str)]: pass\n", "cannot use constraints with ParamSpec"),
+            ("class A[T = int, U]: pass\n", "non-default type parameter 'U' follows default type parameter")
+        ];
+
+        for (source, message) in cases {
+            let mut parser = PythonCoreParser::new(source);
+            assert!(parser.parse_stmt().is_ok(), "{}", source);
+            assert_eq!(parser.lexer.errors.iter().map(|error| error.message.as_str()).collect::<Vec<&str>>(), vec![message], "{}", source)
+        }
+
+        let mut parser = PythonCoreParser::new("class A[T = int, *Ts: int]: pass\n");
+        match &*parser.parse_stmt().unwrap() {
+            SyntaxNode::ClassDefStmtNode( _ , _ , _ , _ , Some(type_params) , .. ) => {
+                assert!(matches!(&**type_params, SyntaxNode::TypeParamsNode( _ , _ , _ , nodes , _ , _ ) if nodes.len() == 2 && matches!(&*nodes[1], SyntaxNode::TypeParamNode( _ , _ , Some( _ ) , _ , Some( _ ) , Some( _ ) , None , None ))))
+            },
+            _ => panic!("Expecting class definition!")
+        }
+        assert_eq!(parser.lexer.errors.len(), 2);
+
+        let mut parser = PythonCoreParser::with_options("def f[T = int](): pass\n", &ParserOptions { target: PythonVersion::Python312, ..ParserOptions::default() });
+        assert!(parser.parse_stmt().is_ok());
+        assert_eq!(parser.lexer.errors.iter().map(|error| error.message.as_str()).collect::<Vec<&str>>(), vec!["type parameter defaults are only supported in Python 3.13 and greater, target is Python 3.12"]);
+    }
+
+    #[test]
+    fn parse_typed_args_list_errors() {
+        let cases = [
+            ("def f(*a, *b): pass\n", "* argument may appear only once"),
+            ("def f(**kw, a): pass\n", "arguments cannot follow var-keyword argument"),
+            ("def f(a, *): pass\n", "named arguments must follow bare *"),
+            ("def f(a=1, b): pass\n", "parameter without a default follows parameter with a default"),
+            ("def f(*a=1): pass\n", "var-positional argument cannot have default value"),
+            ("def f(**a=1): pass\n", "var-keyword argument cannot have default value"),
+            ("def f(/): pass\n", "at least one argument must precede /"),
+            ("def f(a, /, b, /): pass\n", "/ may appear only once"),
+            ("def f(a, *, b, /): pass\n", "/ must be ahead of *"),
+            ("def f(a=1, /, b): pass\n", "parameter without a default follows parameter with a default"),
+            ("x = lambda a, *: a\n", "named arguments must follow bare *"),
+            ("x = lambda /: 1\n", "at least one argument must precede /")
+        ];
+
+        for (source, message) in cases {
+            let mut parser = PythonCoreParser::new(source);
+            assert!(parser.parse_stmt().is_ok(), "{}", source);
+            assert_eq!(parser.lexer.errors.iter().map(|error| error.message.as_str()).collect::<Vec<&str>>(), vec![message], "{}", source)
+        }
+    }
+
+    #[test]
+    fn parse_func_def_with_invalid_parameters() {
+        let mut parser = PythonCoreParser::new("def i(**k, a=1, *b=2): pass
+");
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::FuncDefinitionNode( _ , _ , _ , _ , _ , parameters , _ , _ , _ , _ , _ , _ ) => {
+                assert!(matches!(&**parameters, SyntaxNode::ParametersNode( _ , _ , _ , Some(list) , _ ) if matches!(&**list, SyntaxNode::TypedListNode( _ , _ , nodes ) if nodes.len() == 3)))
+            },
+            _ => panic!("Expecting function definition!")
+        }
+        assert_eq!(parser.lexer.errors.iter().map(|error| error.message.as_str()).collect::<Vec<&str>>(), vec!["arguments cannot follow var-keyword argument", "var-positional argument cannot have default value"]);
+    }
+
+    #[test]
+    fn parse_assignment_and_for_with_type_comments() {
+        let mut parser = PythonCoreParser::with_options("x = []  # type: List[int]\nfor i in x:  # type: int\n    pass\n", &ParserOptions { type_comments: true, ..ParserOptions::default() });
+        let node = parser.parse_file_input().unwrap();
+
+        match &*node {
+            SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ ) => {
+                assert!(matches!(&*nodes[0], SyntaxNode::SimpleStmtNode( _ , _ , stmts , _ , _ ) if matches!(&*stmts[0], SyntaxNode::AssignmentStmtNode( _ , _ , _ , _ , Some(tc) ) if matches!(&**tc, Token::TypeCommentToken( _ , _ , text , _ ) if &**text == "# type: List[int]"))));
+                assert!(matches!(&*nodes[1], SyntaxNode::ForStmtNode( _ , _ , _ , _ , _ , _ , _ , Some( _ ) , _ , None )))
+            },
+            _ => panic!("Expecting file input!")
+        }
+    }
+
+    #[test]
+    fn parse_file_input_with_missing_colon() {
+        let mut parser = PythonCoreParser::new("if x\n    pass\n");
+        let node = parser.parse_file_input().unwrap();
+
+        match &*node {
+            SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ ) => {
+                assert!(matches!(&*nodes[0], SyntaxNode::IfStmtNode( _ , _ , _ , _ , colon , _ , _ , None ) if colon.is_missing() && matches!(&**colon, Token::ColonToken(4, 4, _ )) && colon.text().is_empty()))
+            },
+            _ => panic!("Expecting file input!")
+        }
+        assert_eq!(parser.lexer.errors.len(), 1);
+        assert_eq!(parser.lexer.errors[0].message, "Expecting ':' in 'if' statement!");
+        assert_eq!(parser.lexer.errors[0].code, DiagnosticCode::ExpectedColon);
+        assert!(matches!(&parser.lexer.errors[0].fixes[..], [fix] if (fix.start, fix.end) == (4, 4) && fix.replacement == ":" && fix.message == "Insert ':'"));
+        assert_eq!(parser.lexer.errors[0].expected, vec![":"])
+    }
+
+    #[test]
+    fn parse_file_input_with_error_nodes() {
+        let mut parser = PythonCoreParser::new("x = = 1\ny = 2\ndef f(a b):\n    pass\nw = 3\n");
+        let node = parser.parse_file_input().unwrap();
+
+        match &*node {
+            SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ ) => {
+                assert_eq!(nodes.len(), 4);
+                assert!(matches!(&*nodes[0], SyntaxNode::ErrorNode(0, 8, children, symbols) if children.len() == 2 && symbols.len() == 3));
+                assert!(matches!(&*nodes[1], SyntaxNode::SimpleStmtNode(8, _ , _ , _ , _ )));
+                match &*nodes[2] {
+                    SyntaxNode::ErrorNode(14, 35, children, symbols) => {
+                        assert_eq!(symbols.iter().map(|symbol| symbol.text()).collect::<Vec<String>>(), vec!["def", "f", "(", ")", ":", "\n", "", ""]);
+                        assert_eq!(children.len(), 3);
+                        assert!(matches!(&*children[2], SyntaxNode::SimpleStmtNode(30, _ , _ , _ , _ )))
+                    },
+                    _ => panic!("Expecting error node!")
+                }
+                assert!(matches!(&*nodes[3], SyntaxNode::SimpleStmtNode(35, _ , _ , _ , _ )))
+            },
+            _ => panic!("Expecting file input!")
+        }
+        assert_eq!(parser.lexer.errors.len(), 2);
+        assert_eq!(parser.lexer.errors.iter().map(|error| error.start).collect::<Vec<u32>>(), vec![4, 22]);
+    }
+
+    #[test]
+    fn parse_file_input_with_error_node_over_clauses() {
+        let mut parser = PythonCoreParser::new("if a b:\n    pass\nelif c:\n    pass\nelse:\n    match x:\n        case 1:\n            pass\ny = 2\n");
+        let node = parser.parse_file_input().unwrap();
+
+        match &*node {
+            SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ ) => {
+                assert_eq!(nodes.len(), 2);
+                assert!(matches!(&*nodes[0], SyntaxNode::ErrorNode(0, 86, _ , symbols) if symbols.iter().any(|symbol| matches!(&**symbol, Token::ElseToken(34, 38, _ )))));
+                assert!(matches!(&*nodes[1], SyntaxNode::SimpleStmtNode(86, _ , _ , _ , _ )))
+            },
+            _ => panic!("Expecting file input!")
+        }
+        assert_eq!(parser.lexer.errors.len(), 1);
+        assert_eq!(parser.lexer.errors[0].message, "Expecting ':' in 'if' statement!");
+    }
+
+    #[test]
+    fn parse_block_with_error_node() {
+        let mut parser = PythonCoreParser::new("while x:\n    y = = 1\n    z = 2\n");
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::WhileStmtNode( _ , _ , _ , _ , _ , suite , None ) => {
+                match &**suite {
+                    SyntaxNode::SuiteStmtNode( _ , _ , _ , _ , nodes , _ ) => {
+                        assert_eq!(nodes.len(), 2);
+                        match &*nodes[0] {
+                            SyntaxNode::ErrorNode(13, 25, children, symbols) => {
+                                assert!(matches!(&*children[0], SyntaxNode::TestListStarExprStmtNode(13, _ , _ , _ )));
+                                assert_eq!(symbols.iter().map(|symbol| symbol.full_text()).collect::<String>(), "= = \n")
+                            },
+                            _ => panic!("Expecting error node!")
+                        }
+                        assert!(matches!(&*nodes[1], SyntaxNode::SimpleStmtNode(25, _ , _ , _ , _ )))
+                    },
+                    _ => panic!("Expecting suite!")
+                }
+            },
+            _ => panic!("Expecting while statement!")
+        }
+        assert_eq!(parser.lexer.errors.len(), 1)
+    }
+
+    #[test]
+    fn parse_file_input_with_error_node_without_missing_tokens() {
+        let mut parser = PythonCoreParser::new("x = lambda a b: 1\ny = 2\n");
+        let node = parser.parse_file_input().unwrap();
+
+        match &*node {
+            SyntaxNode::FileInputStmtNode( _ , _ , nodes , _ , _ ) => {
+                assert_eq!(nodes.len(), 2);
+                match &*nodes[0] {
+                    SyntaxNode::ErrorNode(0, 18, children, symbols) => {
+                        assert_eq!(symbols.iter().map(|symbol| symbol.text()).collect::<Vec<String>>(), vec!["=", "lambda", ":", "\n"]);
+                        assert!(symbols.iter().all(|symbol| !symbol.is_missing()));
+                        assert_eq!(children.len(), 4)
+                    },
+                    _ => panic!("Expecting error node!")
+                }
+            },
+            _ => panic!("Expecting file input!")
+        }
+        assert_eq!(parser.lexer.errors.len(), 1)
+    }
+
+    #[test]
+    fn parse_func_def_with_malformed_type_comment() {
+        let mut parser = PythonCoreParser::with_options("def f(a):  # type: int\n    pass\n", &ParserOptions { type_comments: true, ..ParserOptions::default() });
+        let node = parser.parse_stmt().unwrap();
+
+        assert!(matches!(&*node, SyntaxNode::FuncDefinitionNode( _ , _ , _ , _ , _ , _ , _ , _ , _ , Some( _ ) , _ , None )));
+        assert_eq!(parser.lexer.errors.len(), 1);
+        assert_eq!((parser.lexer.errors[0].start, parser.lexer.errors[0].message.as_str()), (19, "Expecting '(' in func type input!"));
+    }
+
+    #[test]
+    fn parse_class_with_missing_paren() {
+        let mut parser = PythonCoreParser::new("class A(B: pass\n");
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::ClassDefStmtNode( _ , _ , _ , _ , _ , Some( _ ) , Some( _ ) , Some(symbol) , _ , _ ) => {
+                assert!(matches!(&**symbol, Token::RightParenToken(9, 9, _ )));
+                assert!(symbol.is_missing())
+            },
+            _ => panic!("Expecting class definition!")
+        }
+        assert_eq!(parser.lexer.errors.iter().map(|error| (error.start, error.message.as_str())).collect::<Vec<(u32, &str)>>(), vec![(9, "Expecting ')' in 'class' statement!"), (7, "'(' was never closed")]);
+    }
 }
@@ -1,6 +1,7 @@
 use crate::parser::python_core_statement_parser::StatementRules;
 use crate::parser::python_core_tokenizer::LexerMethods;
-use crate::parser::syntax_error::SyntaxError;
+use crate::parser::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::parser::expected_tokens::{FIRST_ATOM, FIRST_EXPRESSION, FIRST_FACTOR, FIRST_NOT_TEST, FOLLOW_ARGUMENT, FOLLOW_LIST_ELEMENT, FOLLOW_MAPPING_ELEMENT, FOLLOW_SUBSCRIPT, FOLLOW_TUPLE_ELEMENT};
 use crate::parser::syntax_nodes::SyntaxNode;
 use crate::parser::token_nodes::Token;
 use super::python_core_parser::PythonCoreParser;
@@ -9,54 +10,91 @@ use crate::parser::python_core_block_parser::BlockGrammarRules;
 
 // Trait for expression grammar rule ///////////////////////////////////////////////////////////////
 pub(crate) trait ExpressionRules {
-    fn parse_named_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_test_no_cond_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_lambda_def_expr(&mut self, is_conditional: bool) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_or_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_and_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_not_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_comparison_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_star_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_xor_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_and_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_shift_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_arith_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_term_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_factor_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_power_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_await_atom_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_atom_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_test_list_comp_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_subscript_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_subscript_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_expr_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_test_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_dictionary_set_maker_expr(&mut self, symbol1: Box<Token>, position: u32) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_arg_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_argument_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_comp_iter_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_sync_comp_for_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_comp_for_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_comp_if_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_yield_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_star_expr_named_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_star_expr_named_exp_elements(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
+    fn is_atom_start(&self) -> bool;
+    fn is_factor_start(&self) -> bool;
+    fn is_test_expr_start(&self) -> bool;
+    fn parse_named_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_test_no_cond_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_lambda_def_expr(&mut self, is_conditional: bool) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_or_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_and_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_not_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_comparison_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_star_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_xor_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_and_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_shift_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_arith_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_term_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_factor_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_power_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_await_atom_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_atom_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_string_expr(&mut self, symbol1: Box<Token>, position: u32) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_formatted_string_parts(&mut self) -> Result<Vec<Box<SyntaxNode>>, Box<Diagnostic>>;
+    fn parse_replacement_field_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_test_list_comp_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_subscript_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_subscript_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_expr_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_test_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_dictionary_set_maker_expr(&mut self, symbol1: Box<Token>, position: u32) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_arg_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_argument_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_comp_iter_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_sync_comp_for_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_comp_for_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_comp_if_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_yield_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_star_expr_named_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_star_expr_named_exp_elements(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
 }
 
 // Implementing all expression grammar rules ///////////////////////////////////////////////////////
 impl ExpressionRules for PythonCoreParser {
-    fn parse_named_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    /// Whether the current token can start an atom, the tokens in FIRST_ATOM.
+    fn is_atom_start(&self) -> bool {
+        matches!(&*self.lexer.symbol,
+            Token::NameToken( _ , _ , _ , _ ) |
+            Token::NumberToken( _ , _ , _ , _ ) |
+            Token::StringToken( _ , _ , _ , _ ) |
+            Token::FStringStartToken( _ , _ , _ , _ ) |
+            Token::TStringStartToken( _ , _ , _ , _ ) |
+            Token::LeftParenToken( _ , _ , _ ) |
+            Token::LeftSquareBracketToken( _ , _ , _ ) |
+            Token::LeftCurlyBracketToken( _ , _ , _ ) |
+            Token::EllipsisToken( _ , _ , _ ) |
+            Token::NoneToken( _ , _ , _ ) |
+            Token::TrueToken( _ , _ , _ ) |
+            Token::FalseToken( _ , _ , _ ))
+    }
+
+    /// Whether the current token can start a factor, the tokens in FIRST_FACTOR.
+    fn is_factor_start(&self) -> bool {
+        self.is_atom_start() || matches!(&*self.lexer.symbol,
+            Token::MinusToken( _ , _ , _ ) |
+            Token::PlusToken( _ , _ , _ ) |
+            Token::BitInvertToken( _ , _ , _ ) |
+            Token::AwaitToken( _ , _ , _ ))
+    }
+
+    /// Whether the current token can start a test expression, the tokens in FIRST_EXPRESSION.
+    fn is_test_expr_start(&self) -> bool {
+        self.is_factor_start() || matches!(&*self.lexer.symbol, Token::NotToken( _ , _ , _ ) | Token::LambdaToken( _ , _ , _ ))
+    }
+
+    fn parse_named_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
-        let left = self.parse_expr()?;
+        let left = self.parse_test_expr()?;
 
         match &*self.lexer.symbol {
             Token::ColonAssignToken( _ , _ , _ ) => {
                 let symbol = self.lexer.symbol.clone();
                 self.lexer.advance();
 
-                let right = self.parse_expr()?;
+                let right = self.parse_test_expr()?;
                 Ok(Box::new(SyntaxNode::NamedExprNode(pos, self.lexer.position, left, symbol, right)))
             },
             _ => {
@@ -65,11 +103,12 @@ impl ExpressionRules for PythonCoreParser {
         }
     }
 
-    fn parse_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         match &*self.lexer.symbol {
             Token::LambdaToken( _ , _ , _ ) => {
                 self.parse_lambda_def_expr(true)
             },
+            _ if !self.is_test_expr_start() => Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting valid literal!", FIRST_EXPRESSION)),
             _ => {
                 let pos = self.lexer.position;
                 let left = self.parse_or_test_expr()?;
@@ -86,7 +125,7 @@ impl ExpressionRules for PythonCoreParser {
                                 let next = self.parse_test_expr()?;
                                 Ok(Box::new(SyntaxNode::TestExprNode(pos, self.lexer.position, left, symbol1, right, symbol2, next)))
                             },
-                            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'else' in test expression!"))))
+                            _ => Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting 'else' in test expression!", &["else"]))
                         }
                     },
                     _ => Ok(left)
@@ -95,7 +134,7 @@ impl ExpressionRules for PythonCoreParser {
         }
     }
 
-    fn parse_test_no_cond_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_test_no_cond_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         match &*self.lexer.symbol {
             Token::LambdaToken( _ , _ , _ ) => {
                 self.parse_lambda_def_expr(false)
@@ -104,7 +143,7 @@ impl ExpressionRules for PythonCoreParser {
         }
     }
 
-    fn parse_lambda_def_expr(&mut self, is_conditional: bool) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_lambda_def_expr(&mut self, is_conditional: bool) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol1 = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -116,24 +155,18 @@ impl ExpressionRules for PythonCoreParser {
             _ => Some(self.parse_var_args_list_stmt()?)
         };
 
-        match &*self.lexer.symbol {
-            Token::ColonToken( _ , _ , _ ) => {
-                let symbol2 = self.lexer.symbol.clone();
-                self.lexer.advance();
-                let right = match is_conditional {
-                    true => {
-                        self.parse_test_expr()?
-                    },
-                    _ => self.parse_test_no_cond_expr()?
-                };
-
-                Ok(Box::new(SyntaxNode::LambdaExprNode(pos, self.lexer.position, symbol1, left, symbol2, right, is_conditional)))
+        let symbol2 = self.expect_token(Token::ColonToken, "Expecting ':' in lambda expression!");
+        let right = match is_conditional {
+            true => {
+                self.parse_test_expr()?
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in lambda expression!"))))
-        }
+            _ => self.parse_test_no_cond_expr()?
+        };
+
+        Ok(Box::new(SyntaxNode::LambdaExprNode(pos, self.lexer.position, symbol1, left, symbol2, right, is_conditional)))
     }
 
-    fn parse_or_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_or_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut left = self.parse_and_test_expr()?;
 
@@ -154,7 +187,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(left)
     }
 
-    fn parse_and_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_and_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut left = self.parse_not_test_expr()?;
 
@@ -175,7 +208,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(left)
     }
 
-    fn parse_not_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_not_test_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
 
         match &*self.lexer.symbol {
@@ -186,11 +219,12 @@ impl ExpressionRules for PythonCoreParser {
                 let right = self.parse_not_test_expr()?;
                 Ok(Box::new(SyntaxNode::NotTestExprNode(pos, self.lexer.position, symbol1, right)))
             },
+            _ if !self.is_factor_start() => Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting valid literal!", FIRST_NOT_TEST)),
             _ => self.parse_comparison_expr()
         }
     }
 
-    fn parse_comparison_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_comparison_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut left = self.parse_expr()?;
 
@@ -250,7 +284,7 @@ impl ExpressionRules for PythonCoreParser {
 
                             left = Box::new(SyntaxNode::CompareNotInExprNode(pos, self.lexer.position, left, symbol1, symbol2, right))
                         },
-                        _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'in' in 'not in' compare expression!"))))
+                        _ => return Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting 'in' in 'not in' compare expression!", &["in"]))
                     }
                 }
                 _ => break
@@ -260,7 +294,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(left)
     }
 
-    fn parse_star_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_star_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol1 = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -270,7 +304,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(Box::new(SyntaxNode::StarExprNode(pos, self.lexer.position, symbol1, right)))
     }
 
-    fn parse_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut left = self.parse_xor_expr()?;
 
@@ -291,7 +325,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(left)
     }
 
-    fn parse_xor_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_xor_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut left = self.parse_and_expr()?;
 
@@ -312,7 +346,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(left)
     }
 
-    fn parse_and_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_and_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut left = self.parse_shift_expr()?;
 
@@ -333,7 +367,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(left)
     }
 
-    fn parse_shift_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_shift_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut left = self.parse_arith_expr()?;
 
@@ -358,7 +392,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(left)
     }
 
-    fn parse_arith_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_arith_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut left = self.parse_term_expr()?;
 
@@ -383,7 +417,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(left)
     }
 
-    fn parse_term_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_term_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut left = self.parse_factor_expr()?;
 
@@ -414,7 +448,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(left)
     }
 
-    fn parse_factor_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_factor_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         match &*self.lexer.symbol {
             Token::PlusToken( _ , _ , _ ) |
             Token::MinusToken( _ , _ , _ ) |
@@ -431,11 +465,12 @@ impl ExpressionRules for PythonCoreParser {
                     _ => SyntaxNode::UnaryBitInvertExprNode(pos, self.lexer.position, symbol1, right),
                 }))
             },
+            _ if !self.is_factor_start() => Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting valid literal!", FIRST_FACTOR)),
             _ => self.parse_power_expr()
         }
     }
 
-    fn parse_power_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_power_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let left = self.parse_await_atom_expr()?;
 
@@ -451,7 +486,7 @@ impl ExpressionRules for PythonCoreParser {
         }
     }
 
-    fn parse_await_atom_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_await_atom_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol1 = match &*self.lexer.symbol {
             Token::AwaitToken( _ , _ , _ ) => {
@@ -482,7 +517,7 @@ impl ExpressionRules for PythonCoreParser {
 
                             trailers.push(Box::new(SyntaxNode::TrailerCallExprNode(pos, self.lexer.position, symbol2, next, symbol3)))
                         },
-                        _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ')' in call trailer!"))))
+                        _ => return Err(self.error_expecting(DiagnosticCode::ExpectedClosingBracket, "Expecting ')' in call trailer!", FOLLOW_ARGUMENT))
                     }
                 },
                 Token::LeftSquareBracketToken( _ , _ , _ ) => {
@@ -498,7 +533,7 @@ impl ExpressionRules for PythonCoreParser {
 
                             trailers.push(Box::new(SyntaxNode::TrailerIndexExprNode(pos, self.lexer.position, symbol2, next, symbol3)))
                         },
-                        _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ']' in index trailer!"))))
+                        _ => return Err(self.error_expecting(DiagnosticCode::ExpectedClosingBracket, "Expecting ']' in index trailer!", FOLLOW_SUBSCRIPT))
                     }
                 },
                 Token::PeriodToken( _ , _ , _ ) => {
@@ -510,48 +545,75 @@ impl ExpressionRules for PythonCoreParser {
                             let next = self.parse_atom_expr()?;
                             trailers.push(Box::new(SyntaxNode::TrailerDotNameExprNode(pos, self.lexer.position, symbol2, next)))
                         }
-                        _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME literal after '.' in trailer!"))))
+                        _ => return Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME literal after '.' in trailer!", &["NAME"]))
                     }
                 },
                 _ => break
             }
         }
 
-        trailers.reverse();
-
         match symbol1.is_none() && trailers.is_empty() {
-            true => Ok(Box::new(SyntaxNode::AtomExprNode(pos, self.lexer.position, symbol1, right, trailers))),
-            _ => Ok(right)
+            true => Ok(right),
+            _ => Ok(Box::new(SyntaxNode::AtomExprNode(pos, self.lexer.position, symbol1, right, trailers)))
         }
     }
 
-    fn parse_atom_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_atom_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
+
+        /* Report a token that cannot start an atom before consuming it */
+        if !self.is_atom_start() {
+            return Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting valid literal!", FIRST_ATOM))
+        }
+
         let symbol1 = self.lexer.symbol.clone();
         self.lexer.advance();
 
-        match &*self.lexer.symbol {
+        match &*symbol1 {
             Token::NameToken( _ , _ , _ , _ ) => Ok(Box::new(SyntaxNode::NameExprNode(pos, self.lexer.position, symbol1))),
             Token::NumberToken( _ , _ , _ , _ ) => Ok(Box::new(SyntaxNode::NumberExprNode(pos, self.lexer.position, symbol1))),
             Token::NoneToken( _ , _ , _ ) => Ok(Box::new(SyntaxNode::NoneExprNode(pos, self.lexer.position, symbol1))),
             Token::FalseToken( _ , _ , _ ) => Ok(Box::new(SyntaxNode::FalseExprNode(pos, self.lexer.position, symbol1))),
             Token::TrueToken( _ , _ , _ ) => Ok(Box::new(SyntaxNode::TrueExprNode(pos, self.lexer.position, symbol1))),
             Token::EllipsisToken( _ , _ , _ ) => Ok(Box::new(SyntaxNode::EllipsisExprNode(pos, self.lexer.position, symbol1))),
-            Token::StringToken( _ , _ , _ , _ ) => {
-                let mut nodes = Vec::<Box<Token>>::new();
-                nodes.push(symbol1);
+            Token::StringToken( _ , _ , _ , _ ) |
+            Token::FStringStartToken( _ , _ , _ , _ ) |
+            Token::TStringStartToken( _ , _ , _ , _ ) => {
+                let mut nodes = Vec::<Box<SyntaxNode>>::new();
+                nodes.push(self.parse_string_expr(symbol1, pos)?);
                 loop {
                     match &*self.lexer.symbol {
-                        Token::StringToken( _ , _ , _ , _ ) => {
+                        Token::StringToken( _ , _ , _ , _ ) |
+                        Token::FStringStartToken( _ , _ , _ , _ ) |
+                        Token::TStringStartToken( _ , _ , _ , _ ) => {
+                            let pos2 = self.lexer.position;
                             let symbol2 = self.lexer.symbol.clone();
                             self.lexer.advance();
-                            nodes.push(symbol2)
+                            nodes.push(self.parse_string_expr(symbol2, pos2)?)
                         }
                         _ => break
                     }
                 }
-                nodes.reverse();
-                Ok(Box::new(SyntaxNode::StringExprNode(pos, self.lexer.position, nodes)))
+
+                let templates = nodes.iter().filter(|node| matches!(&***node, SyntaxNode::TemplateStringExprNode( _ , _ , _ , _ , _ ))).count();
+                if templates > 0 && templates < nodes.len() {
+                    return Err(Box::new(Diagnostic::error(DiagnosticCode::InvalidString, pos, self.lexer.position, String::from("cannot mix t-string literals with string or bytes literals"))))
+                }
+
+                /* Plain strings stay a flat list of tokens, f-strings make it a list of nodes */
+                match nodes.iter().all(|node| matches!(&**node, SyntaxNode::StringExprNode( _ , _ , _ ))) {
+                    true => {
+                        let mut strings = Vec::<Box<Token>>::new();
+                        for node in nodes {
+                            if let SyntaxNode::StringExprNode( _ , _ , tokens) = *node {
+                                strings.extend(tokens)
+                            }
+                        }
+                        Ok(Box::new(SyntaxNode::StringExprNode(pos, self.lexer.position, strings)))
+                    },
+                    _ if nodes.len() == 1 => Ok(nodes.pop().unwrap()),
+                    _ => Ok(Box::new(SyntaxNode::ConcatenatedStringExprNode(pos, self.lexer.position, nodes)))
+                }
             },
             Token::LeftParenToken( _ , _ , _ ) => {
                 let right = match &*self.lexer.symbol {
@@ -563,31 +625,143 @@ impl ExpressionRules for PythonCoreParser {
                     Token::RightParenToken( _ , _ , _ ) => {
                         let symbol2 = self.lexer.symbol.clone();
                         self.lexer.advance();
-                        Ok(Box::new(SyntaxNode::TupleExprNode(pos, self.lexer.position, symbol1, None, symbol2)))
+                        Ok(Box::new(SyntaxNode::TupleExprNode(pos, self.lexer.position, symbol1, right, symbol2)))
                     }
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ')' in literal!"))))
+                    _ => Err(self.error_expecting(DiagnosticCode::ExpectedClosingBracket, "Expecting ')' in literal!", FOLLOW_TUPLE_ELEMENT))
                 }
             },
             Token::LeftSquareBracketToken( _ , _ , _ ) => {
                 let right = match &*self.lexer.symbol {
-                    Token::RightParenToken( _ , _ , _ ) => None,
+                    Token::RightSquareBracketToken( _ , _ , _ ) => None,
                     _ => Some(self.parse_test_list_comp_expr()?)
                 };
                 match &*self.lexer.symbol {
                     Token::RightSquareBracketToken( _ , _ , _ ) => {
                         let symbol2 = self.lexer.symbol.clone();
                         self.lexer.advance();
-                        Ok(Box::new(SyntaxNode::ListExprNode(pos, self.lexer.position, symbol1, None, symbol2)))
+                        Ok(Box::new(SyntaxNode::ListExprNode(pos, self.lexer.position, symbol1, right, symbol2)))
                     }
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ')' in literal!"))))
+                    _ => Err(self.error_expecting(DiagnosticCode::ExpectedClosingBracket, "Expecting ']' in literal!", FOLLOW_LIST_ELEMENT))
+                }
+            },
+            _ => self.parse_dictionary_set_maker_expr(symbol1, pos)
+        }
+    }
+
+    fn parse_string_expr(&mut self, symbol1: Box<Token>, position: u32) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+        match &*symbol1 {
+            Token::FStringStartToken( _ , _ , _ , _ ) => {
+                let parts = self.parse_formatted_string_parts()?;
+
+                match &*self.lexer.symbol {
+                    Token::FStringEndToken( _ , _ , _ , _ ) => {
+                        let symbol2 = self.lexer.symbol.clone();
+                        self.lexer.advance();
+
+                        Ok(Box::new(SyntaxNode::FormattedStringExprNode(position, self.lexer.position, symbol1, parts, symbol2)))
+                    },
+                    _ => Err(self.error(DiagnosticCode::InvalidFString, "Expecting end of f-string!"))
+                }
+            },
+            Token::TStringStartToken( _ , _ , _ , _ ) => {
+                let parts = self.parse_formatted_string_parts()?;
+
+                match &*self.lexer.symbol {
+                    Token::TStringEndToken( _ , _ , _ , _ ) => {
+                        let symbol2 = self.lexer.symbol.clone();
+                        self.lexer.advance();
+
+                        Ok(Box::new(SyntaxNode::TemplateStringExprNode(position, self.lexer.position, symbol1, parts, symbol2)))
+                    },
+                    _ => Err(self.error(DiagnosticCode::InvalidFString, "Expecting end of t-string!"))
+                }
+            },
+            _ => Ok(Box::new(SyntaxNode::StringExprNode(position, self.lexer.position, vec![symbol1])))
+        }
+    }
+
+    fn parse_formatted_string_parts(&mut self) -> Result<Vec<Box<SyntaxNode>>, Box<Diagnostic>> {
+        let mut nodes = Vec::<Box<SyntaxNode>>::new();
+
+        loop {
+            match &*self.lexer.symbol {
+                Token::FStringMiddleToken( _ , _ , _ , _ ) |
+                Token::TStringMiddleToken( _ , _ , _ , _ ) => {
+                    let pos = self.lexer.position;
+                    let symbol = self.lexer.symbol.clone();
+                    self.lexer.advance();
+
+                    nodes.push(Box::new(SyntaxNode::FormattedStringMiddleNode(pos, self.lexer.position, symbol)))
+                },
+                Token::LeftCurlyBracketToken( _ , _ , _ ) => nodes.push(self.parse_replacement_field_expr()?),
+                _ => break
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    fn parse_replacement_field_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+        let pos = self.lexer.position;
+        let symbol1 = self.lexer.symbol.clone();
+        self.lexer.advance();
+
+        let right = match &*self.lexer.symbol {
+            Token::RightCurlyBracketToken( _ , _ , _ ) => return Err(self.error(DiagnosticCode::InvalidFString, "f-string: valid expression required before '}'")),
+            Token::YieldToken( _ , _ , _ ) => self.parse_yield_expr()?,
+            _ => self.parse_test_list_expr()?
+        };
+
+        let symbol2 = match &*self.lexer.symbol {
+            Token::AssignToken( _ , _ , _ ) => {
+                let symbol2 = self.lexer.symbol.clone();
+                self.lexer.advance();
+                Some(symbol2)
+            },
+            _ => None
+        };
+
+        let (symbol3, conversion) = match &*self.lexer.symbol {
+            Token::ExclamationToken( _ , _ , _ ) => {
+                let symbol3 = self.lexer.symbol.clone();
+                self.lexer.advance();
+
+                match &*self.lexer.symbol {
+                    Token::NameToken(s, _ , text, _ ) if matches!(text.as_str(), "r" | "s" | "a") => {
+                        if *s != symbol3.span().1 {
+                            return Err(self.error(DiagnosticCode::InvalidFString, "f-string: conversion type must come right after the exclamation mark"))
+                        }
+                        let conversion = self.lexer.symbol.clone();
+                        self.lexer.advance();
+                        (Some(symbol3), Some(conversion))
+                    },
+                    _ => return Err(self.error(DiagnosticCode::InvalidFString, "f-string: invalid conversion character: expected 's', 'r', or 'a'"))
                 }
             },
-            Token::LeftCurlyBracketToken( _ , _ , _ ) => self.parse_dictionary_set_maker_expr(symbol1, pos),
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting valid literal!"))))
+            _ => (None, None)
+        };
+
+        let (symbol4, format_spec) = match &*self.lexer.symbol {
+            Token::ColonToken( _ , _ , _ ) => {
+                let symbol4 = self.lexer.symbol.clone();
+                self.lexer.advance();
+                (Some(symbol4), self.parse_formatted_string_parts()?)
+            },
+            _ => (None, Vec::new())
+        };
+
+        match &*self.lexer.symbol {
+            Token::RightCurlyBracketToken( _ , _ , _ ) => {
+                let symbol5 = self.lexer.symbol.clone();
+                self.lexer.advance();
+
+                Ok(Box::new(SyntaxNode::ReplacementFieldNode(pos, self.lexer.position, symbol1, right, symbol2, symbol3, conversion, symbol4, format_spec, symbol5)))
+            },
+            _ => Err(self.error_expecting(DiagnosticCode::InvalidFString, "f-string: expecting '}'", &["}"]))
         }
     }
 
-    fn parse_test_list_comp_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_test_list_comp_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -626,16 +800,13 @@ impl ExpressionRules for PythonCoreParser {
             }
         }
 
-        nodes.reverse();
-        separators.reverse();
-
         Ok(match nodes.len() == 1 && separators.len() == 0 {
             true => nodes.pop().unwrap(),
             _ => Box::new(SyntaxNode::TestListComprehensionExprNode(pos, self.lexer.position, nodes, separators)),
         })
     }
 
-    fn parse_subscript_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_subscript_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -659,16 +830,13 @@ impl ExpressionRules for PythonCoreParser {
             }
         }
 
-        nodes.reverse();
-        separators.reverse();
-
         Ok(match nodes.len() == 1 && separators.len() == 0 {
             true => nodes.pop().unwrap(),
             _ => Box::new(SyntaxNode::SubscriptListExprNode(pos, self.lexer.position, nodes, separators)),
         })
     }
 
-    fn parse_subscript_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_subscript_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let first = match &*self.lexer.symbol {
            Token::ColonToken( _ , _ , _ ) => None,
@@ -713,7 +881,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(Box::new(SyntaxNode::SubscriptExprNode(pos, self.lexer.position, first, symbol1, second, symbol2, third)))
     }
 
-    fn parse_expr_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_expr_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -741,16 +909,13 @@ impl ExpressionRules for PythonCoreParser {
             }
         }
 
-        nodes.reverse();
-        separators.reverse();
-
         Ok(match nodes.len() == 1 && separators.len() == 0 {
             true => nodes.pop().unwrap(),
             _ => Box::new(SyntaxNode::ExprListExprNode(pos, self.lexer.position, nodes, separators)),
         })
     }
 
-    fn parse_test_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_test_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -765,7 +930,8 @@ impl ExpressionRules for PythonCoreParser {
 
                     match &*self.lexer.symbol {
                         Token::SemicolonToken( _ , _ , _ )|
-                        Token::NewlineToken( _ , _ , _ , _ , _ ) => break,
+                        Token::NewlineToken( _ , _ , _ , _ , _ ) |
+                        Token::RightCurlyBracketToken( _ , _ , _ ) => break,
                         _ => nodes.push(self.parse_test_expr()?)
                     }
                 },
@@ -773,16 +939,13 @@ impl ExpressionRules for PythonCoreParser {
             }
         }
 
-        nodes.reverse();
-        separators.reverse();
-
         Ok(match nodes.len() == 1 && separators.len() == 0 {
             true => nodes.pop().unwrap(),
             _ => Box::new(SyntaxNode::TestListExprNode(pos, self.lexer.position, nodes, separators)),
         })
     }
 
-    fn parse_dictionary_set_maker_expr(&mut self, symbol1: Box<Token>, position: u32) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_dictionary_set_maker_expr(&mut self, symbol1: Box<Token>, position: u32) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let mut is_dictionary = true;
 
         match &*self.lexer.symbol {
@@ -841,7 +1004,7 @@ impl ExpressionRules for PythonCoreParser {
                         match is_dictionary {
                             true => {
                                 loop {
-                                    let mut pos2 = self.lexer.position;
+                                    let pos2 = self.lexer.position;
                                     match &*self.lexer.symbol {
                                         Token::CommaToken( _ , _ , _ ) => {
                                             separators.push(self.lexer.symbol.clone());
@@ -859,13 +1022,13 @@ impl ExpressionRules for PythonCoreParser {
                                                 _ => {
                                                     let left = self.parse_test_expr()?;
                                                     match &*self.lexer.symbol {
-                                                        Token::SemicolonToken( _ , _ , _ ) => {
+                                                        Token::ColonToken( _ , _ , _ ) => {
                                                             let symbol3 = self.lexer.symbol.clone();
                                                             self.lexer.advance();
                                                             let right = self.parse_test_expr()?;
                                                             nodes.push(Box::new(SyntaxNode::DictionaryEntryNode(pos2, self.lexer.position, left, symbol3, right)))
                                                         },
-                                                        _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in dictionary element!"))))
+                                                        _ => return Err(self.error_expecting(DiagnosticCode::ExpectedColon, "Expecting ':' in dictionary element!", &[":"]))
                                                     }
                                                 }
                                             }
@@ -904,12 +1067,12 @@ impl ExpressionRules for PythonCoreParser {
 
                 /* End it up */
 
-                let symbol2 = self.lexer.symbol.clone();
+                let symbol2 = match &*self.lexer.symbol {
+                    Token::RightCurlyBracketToken( _ , _ , _ ) => self.lexer.symbol.clone(),
+                    _ => return Err(self.error_expecting(DiagnosticCode::ExpectedClosingBracket, "Expecting '}' in dictionary or set!", FOLLOW_MAPPING_ELEMENT))
+                };
                 self.lexer.advance();
 
-                nodes.reverse();
-                separators.reverse();
-
                 match is_dictionary {
                     true => Ok(Box::new(SyntaxNode::DictionaryExprNode(position, self.lexer.position, symbol1, nodes, separators, symbol2))),
                     _ => Ok(Box::new(SyntaxNode::SetExprNode(position, self.lexer.position, symbol1, nodes, separators, symbol2)))
@@ -918,7 +1081,7 @@ impl ExpressionRules for PythonCoreParser {
         }
     }
 
-    fn parse_arg_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_arg_list_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -940,16 +1103,13 @@ impl ExpressionRules for PythonCoreParser {
             }
         }
 
-        nodes.reverse();
-        separators.reverse();
-
         Ok(match nodes.len() == 1 && separators.len() == 0 {
             true => nodes.pop().unwrap(),
             _ => Box::new(SyntaxNode::ArgListExprNode(pos, self.lexer.position, nodes, separators)),
         })
     }
 
-    fn parse_argument_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_argument_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
 
         match &*self.lexer.symbol {
@@ -958,24 +1118,24 @@ impl ExpressionRules for PythonCoreParser {
                 let symbol = self.lexer.symbol.clone();
                 self.lexer.advance();
 
-                match &*self.lexer.symbol {
-                    Token::NameToken( _ , _ , _ , _ ) => {
-                        let right = self.parse_atom_expr()?;
-                        Ok(Box::new(SyntaxNode::ArgumentExprNode(pos, self.lexer.position, None, Some(symbol), Some(right))))
-                    },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME literal in argument after '*' or '**'!"))))
-                }
+                let right = self.parse_test_expr()?;
+                Ok(Box::new(SyntaxNode::ArgumentExprNode(pos, self.lexer.position, None, Some(symbol), Some(right))))
             },
-            Token::NameToken( _ , _ , _ , _ ) => {
-                let left = self.parse_atom_expr()?;
+            _ => {
+                let left = self.parse_test_expr()?;
 
                 match &*self.lexer.symbol {
-                    Token::ColonToken( _ , _ , _ ) |
-                    Token::AssignToken( _ , _ , _ )=> {
-                        let symbol = self.lexer.symbol.clone();
-                        self.lexer.advance();
-                        let right = self.parse_atom_expr()?;
-                        Ok(Box::new(SyntaxNode::ArgumentExprNode(pos, self.lexer.position, Some(left), Some(symbol), Some(right))))
+                    Token::ColonAssignToken( _ , _ , _ ) |
+                    Token::AssignToken( _ , _ , _ ) => {
+                        match &*left {
+                            SyntaxNode::NameExprNode( .. ) => {
+                                let symbol = self.lexer.symbol.clone();
+                                self.lexer.advance();
+                                let right = self.parse_test_expr()?;
+                                Ok(Box::new(SyntaxNode::ArgumentExprNode(pos, self.lexer.position, Some(left), Some(symbol), Some(right))))
+                            },
+                            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME literal before '=' or ':=' in argument!", &["NAME"]))
+                        }
                     },
                     Token::AsyncToken( _ , _ , _ ) |
                     Token::ForToken( _ , _ , _ )=> {
@@ -984,12 +1144,11 @@ impl ExpressionRules for PythonCoreParser {
                     },
                     _ => Ok(left)
                 }
-            },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME literal in argument!"))))
+            }
         }
     }
 
-    fn parse_comp_iter_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_comp_iter_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         match &*self.lexer.symbol {
             Token::ForToken( _ , _ , _ ) => self.parse_sync_comp_for_expr(),
             Token::AsyncToken( _ , _ , _ ) => self.parse_comp_for_expr(),
@@ -997,7 +1156,7 @@ impl ExpressionRules for PythonCoreParser {
         }
     }
 
-    fn parse_sync_comp_for_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_sync_comp_for_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol1 = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -1006,9 +1165,6 @@ impl ExpressionRules for PythonCoreParser {
 
         match &*self.lexer.symbol {
             Token::InToken( _ , _ , _ ) => {
-                Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'in' in comprehension 'for' expression!"))))
-            },
-            _ => {
                 let symbol2 = self.lexer.symbol.clone();
                 self.lexer.advance();
 
@@ -1024,11 +1180,16 @@ impl ExpressionRules for PythonCoreParser {
                 };
 
                 Ok(Box::new(SyntaxNode::SyncCompForExprNode(pos, self.lexer.position, symbol1, left, symbol2, right, next)))
-            }
+            },
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting 'in' in comprehension 'for' expression!", &["in"]))
         }
     }
 
-    fn parse_comp_for_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_comp_for_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+        if let Token::ForToken( _ , _ , _ ) = &*self.lexer.symbol {
+            return self.parse_sync_comp_for_expr()
+        }
+
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -1047,7 +1208,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(Box::new(SyntaxNode::CompForExprNode(pos, self.lexer.position, symbol, right, next)))
     }
 
-    fn parse_comp_if_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_comp_if_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -1066,7 +1227,7 @@ impl ExpressionRules for PythonCoreParser {
         Ok(Box::new(SyntaxNode::CompIfExprNode(pos, self.lexer.position, symbol, right, next)))
     }
 
-    fn parse_yield_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_yield_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol1 = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -1088,14 +1249,14 @@ impl ExpressionRules for PythonCoreParser {
         }
     }
 
-    fn parse_star_expr_named_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_star_expr_named_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         match &*self.lexer.symbol {
             Token::MultiplyToken( _ , _ , _ ) => self.parse_star_expr(),
             _ => self.parse_named_expr()
         }
     }
 
-    fn parse_star_expr_named_exp_elements(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_star_expr_named_exp_elements(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -1117,9 +1278,6 @@ impl ExpressionRules for PythonCoreParser {
             }
         }
 
-        nodes.reverse();
-        separators.reverse();
-
         Ok(Box::new(SyntaxNode::StarExprNamedExprListNode(pos, self.lexer.position, nodes, separators)))
     }
 }
@@ -1127,8 +1285,177 @@ impl ExpressionRules for PythonCoreParser {
 // Unittests for expression grammar rules //////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use crate::parser::expected_tokens::{FIRST_ATOM, FIRST_EXPRESSION, FIRST_FACTOR, FIRST_NOT_TEST};
+    use crate::parser::python_core_expression_parser::ExpressionRules;
+    use crate::parser::python_core_parser::PythonCoreParser;
+    use crate::parser::syntax_nodes::SyntaxNode;
+    use crate::parser::token_nodes::Token;
+
     #[test]
     fn dummy_test() {
         assert_eq!(1 == 1, true);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn parse_adjacent_string_literals() {
+        let mut parser = PythonCoreParser::new("'a' r\"b\" '''c'''\n");
+        let node = parser.parse_atom_expr().unwrap();
+
+        match &*node {
+            SyntaxNode::StringExprNode(0, 16, nodes) => {
+                assert_eq!(nodes.len(), 3);
+                assert!(matches!(&*nodes[0], Token::StringToken(0, 3, _, _)));
+                assert!(matches!(&*nodes[1], Token::StringToken(4, 8, _, _)));
+                assert!(matches!(&*nodes[2], Token::StringToken(9, 16, _, _)));
+            },
+            _ => panic!("expected string expression")
+        }
+    }
+
+    #[test]
+    fn parse_formatted_string() {
+        let mut parser = PythonCoreParser::new("f'a{x!r:>{width}}b{y=}'\n");
+        let node = parser.parse_atom_expr().unwrap();
+
+        match &*node {
+            SyntaxNode::FormattedStringExprNode(0, 23, start, parts, end) => {
+                assert!(matches!(&**start, Token::FStringStartToken(0, 2, _, _)));
+                assert!(matches!(&**end, Token::FStringEndToken(22, 23, _, _)));
+                assert_eq!(parts.len(), 4);
+                assert!(matches!(&*parts[0], SyntaxNode::FormattedStringMiddleNode(2, 3, _)));
+                match &*parts[1] {
+                    SyntaxNode::ReplacementFieldNode(3, 17, _, right, None, Some(_), Some(conversion), Some(_), format_spec, _) => {
+                        assert!(matches!(&**right, SyntaxNode::NameExprNode(4, 5, name) if matches!(&**name, Token::NameToken(_, _, text, _) if text.as_str() == "x")));
+                        assert!(matches!(&**conversion, Token::NameToken(6, 7, _, _)));
+                        assert_eq!(format_spec.len(), 2);
+                        assert!(matches!(&*format_spec[1], SyntaxNode::ReplacementFieldNode(9, 16, _, _, None, None, None, None, _, _)));
+                    },
+                    _ => panic!("expected replacement field")
+                }
+                assert!(matches!(&*parts[3], SyntaxNode::ReplacementFieldNode(18, 22, _, _, Some(_), None, None, None, _, _)));
+            },
+            _ => panic!("expected f-string")
+        }
+    }
+
+    #[test]
+    fn parse_concatenated_formatted_strings() {
+        let mut parser = PythonCoreParser::new("'a' f'{b}' 'c'\n");
+        let node = parser.parse_atom_expr().unwrap();
+
+        match &*node {
+            SyntaxNode::ConcatenatedStringExprNode(0, 14, nodes) => {
+                assert_eq!(nodes.len(), 3);
+                assert!(matches!(&*nodes[0], SyntaxNode::StringExprNode(0, 4, _)));
+                assert!(matches!(&*nodes[1], SyntaxNode::FormattedStringExprNode(4, 11, _, _, _)));
+                assert!(matches!(&*nodes[2], SyntaxNode::StringExprNode(11, 14, _)));
+            },
+            _ => panic!("expected concatenated strings")
+        }
+    }
+
+    #[test]
+    fn parse_formatted_string_errors() {
+        let mut parser = PythonCoreParser::new("f'{}'\n");
+        let error = parser.parse_atom_expr().err().unwrap();
+        assert_eq!(error.message, "f-string: valid expression required before '}'");
+
+        let mut parser = PythonCoreParser::new("f'{x!z}'\n");
+        let error = parser.parse_atom_expr().err().unwrap();
+        assert_eq!(error.message, "f-string: invalid conversion character: expected 's', 'r', or 'a'");
+    }
+
+    #[test]
+    fn parse_template_string() {
+        let mut parser = PythonCoreParser::new("t'a{x!r}' t'b'\n");
+        let node = parser.parse_atom_expr().unwrap();
+
+        match &*node {
+            SyntaxNode::ConcatenatedStringExprNode(0, 14, nodes) => {
+                assert!(matches!(&*nodes[0], SyntaxNode::TemplateStringExprNode(0, 10, _, parts, _) if parts.len() == 2));
+                assert!(matches!(&*nodes[1], SyntaxNode::TemplateStringExprNode(10, 14, _, _, _)));
+            },
+            _ => panic!("expected template strings")
+        }
+
+        let mut parser = PythonCoreParser::new("t'a' 'b'\n");
+        let error = parser.parse_atom_expr().err().unwrap();
+        assert_eq!(error.message, "cannot mix t-string literals with string or bytes literals");
+    }
+
+    #[test]
+    fn parse_errors_with_expected_tokens() {
+        let mut parser = PythonCoreParser::new("(a b)\n");
+        let error = parser.parse_atom_expr().err().unwrap();
+        assert_eq!(error.expected, vec![")", ",", "for", "async"]);
+        assert_eq!(error.expected_message().unwrap(), "expected one of `)`, `,`, `for`, `async`");
+
+        let mut parser = PythonCoreParser::new("f(a b)\n");
+        let error = parser.parse_await_atom_expr().err().unwrap();
+        assert_eq!(error.expected, vec![")", ","]);
+
+        let mut parser = PythonCoreParser::new("[x for x y]\n");
+        let error = parser.parse_atom_expr().err().unwrap();
+        assert_eq!(error.message, "Expecting 'in' in comprehension 'for' expression!");
+        assert_eq!(error.expected_message().unwrap(), "expected `in`");
+
+        let mut parser = PythonCoreParser::new("{1: 2 3}\n");
+        let error = parser.parse_atom_expr().err().unwrap();
+        assert_eq!(error.expected, vec!["}", ","]);
+    }
+
+    #[test]
+    fn parse_errors_with_expected_expression_starts() {
+        let mut parser = PythonCoreParser::new("f(:)\n");
+        let error = parser.parse_test_expr().err().unwrap();
+        assert_eq!(error.start, 2);
+        assert_eq!(error.expected, FIRST_EXPRESSION);
+        assert!(["FSTRING_START", "TSTRING_START", "-", "+", "~", "await", "not", "lambda"].iter().all(|token| error.expected.contains(&String::from(*token))));
+
+        let mut parser = PythonCoreParser::new("a and :\n");
+        let error = parser.parse_test_expr().err().unwrap();
+        assert_eq!(error.expected, FIRST_NOT_TEST);
+
+        let mut parser = PythonCoreParser::new("a + :\n");
+        let error = parser.parse_test_expr().err().unwrap();
+        assert_eq!(error.expected, FIRST_FACTOR);
+
+        let mut parser = PythonCoreParser::new("await :\n");
+        let error = parser.parse_test_expr().err().unwrap();
+        assert_eq!(error.expected, FIRST_ATOM);
+    }
+
+    #[test]
+    fn parse_dictionary_and_comprehension() {
+        let mut parser = PythonCoreParser::new("{1: 2, 3: 4}\n");
+        let node = parser.parse_atom_expr().unwrap();
+        assert!(matches!(&*node, SyntaxNode::DictionaryExprNode(0, 12, _ , nodes, separators, _ ) if nodes.len() == 2 && separators.len() == 1 && matches!(&*nodes[0], SyntaxNode::DictionaryEntryNode(1, 5, _ , _ , _ ))));
+
+        let mut parser = PythonCoreParser::new("[x for x in y]\n");
+        let node = parser.parse_atom_expr().unwrap();
+        assert!(matches!(&*node, SyntaxNode::ListExprNode(0, 14, _ , Some( _ ), _ )));
+    }
+
+    #[test]
+    fn parse_boolean_operators_in_brackets() {
+        let mut parser = PythonCoreParser::new("(a or not b)\n");
+        let node = parser.parse_atom_expr().unwrap();
+        assert!(matches!(&*node, SyntaxNode::TupleExprNode(0, 12, _ , Some(right), _ ) if matches!(&**right, SyntaxNode::OrTestExprNode( _ , _ , _ , _ , next ) if matches!(&**next, SyntaxNode::NotTestExprNode( .. )))));
+
+        let mut parser = PythonCoreParser::new("[y := a if b else c]\n");
+        let node = parser.parse_atom_expr().unwrap();
+        assert!(matches!(&*node, SyntaxNode::ListExprNode( _ , _ , _ , Some(right), _ ) if matches!(&**right, SyntaxNode::NamedExprNode( _ , _ , _ , _ , next ) if matches!(&**next, SyntaxNode::TestExprNode( .. )))));
+    }
+
+    #[test]
+    fn parse_elements_in_source_order() {
+        let mut parser = PythonCoreParser::new("a[1, 2]\n");
+        let node = parser.parse_await_atom_expr().unwrap();
+        assert!(matches!(&*node, SyntaxNode::AtomExprNode( _ , _ , None , _ , trailers ) if matches!(&*trailers[0], SyntaxNode::TrailerIndexExprNode( _ , _ , _ , next , _ )
+            if matches!(&**next, SyntaxNode::SubscriptListExprNode( _ , _ , nodes , _ ) if nodes.len() == 2 && matches!(&*nodes[0], SyntaxNode::SubscriptExprNode(2, .. ))))));
+
+        let mut parser = PythonCoreParser::new("(1, 2)\n");
+        let node = parser.parse_atom_expr().unwrap();
+        assert!(matches!(&*node, SyntaxNode::TupleExprNode( _ , _ , _ , Some(right) , _ ) if matches!(&**right, SyntaxNode::TestListComprehensionExprNode( _ , _ , nodes , _ ) if matches!(&*nodes[0], SyntaxNode::NumberExprNode(1, 2, _ )))));
+    }
+}
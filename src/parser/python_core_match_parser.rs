@@ -1,41 +1,43 @@
-use crate::parser::python_core_block_parser::BlockGrammarRules;
 use crate::parser::python_core_expression_parser::ExpressionRules;
 use crate::parser::python_core_parser::PythonCoreParser;
 use crate::parser::python_core_statement_parser::StatementRules;
 use crate::parser::python_core_tokenizer::LexerMethods;
-use crate::parser::syntax_error::SyntaxError;
+use crate::parser::python_version::Feature;
+use crate::parser::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::parser::expected_tokens::{FIRST_MAPPING_KEY, FIRST_PATTERN, FOLLOW_CASE_BLOCK, FOLLOW_MAPPING_ELEMENT, FOLLOW_MATCH_SUBJECT};
 use crate::parser::syntax_nodes::SyntaxNode;
 use crate::parser::token_nodes::Token;
 use crate::parser::token_nodes::Token::DefaultToken;
 
 pub(crate) trait MatchPatternRules {
-    fn parse_match_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_subject_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_case_block(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_guard_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_patterns(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_as_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_or_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_closed_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-
-    fn parse_wildcard_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_sequence_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_mappings_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_key_value_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_attr_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_power_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-
-
-    fn parse_class_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-
-    fn parse_capture_target(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-
-    fn parse_open_sequence_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_star_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
+    fn parse_match_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn is_match_stmt(&mut self) -> bool;
+    fn parse_subject_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_case_block(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_guard_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_patterns(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_as_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_or_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_closed_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+
+    fn parse_wildcard_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_sequence_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_mappings_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_key_value_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_attr_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_power_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+
+
+    fn parse_class_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+
+    fn parse_capture_target(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+
+    fn parse_open_sequence_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_star_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
 }
 
 impl MatchPatternRules for PythonCoreParser {
-    fn parse_match_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_match_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
 
@@ -43,11 +45,13 @@ impl MatchPatternRules for PythonCoreParser {
             Token::NameToken( s, e, text, t) => {
                 match text.as_str() {
                     "match" => Box::new(Token::MatchToken(*s, *e, t.clone())),
-                    _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'match' keyword in match statement!"))))
+                    _ => return Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting 'match' keyword in match statement!", &["match"]))
                 }
             },
-            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'match' keyword in match statement!"))))
+            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting 'match' keyword in match statement!", &["match"]))
         };
+        self.lexer.require_feature(Feature::PatternMatching, pos);
+        self.lexer.advance();
 
         let right = self.parse_subject_expr()?;
 
@@ -62,7 +66,7 @@ impl MatchPatternRules for PythonCoreParser {
                         self.lexer.advance();
                         symbol10
                     },
-                    _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NEWLINE in 'match' statement!"))))
+                    _ => return Err(self.error_expecting(DiagnosticCode::ExpectedNewline, "Expecting NEWLINE in 'match' statement!", &["NEWLINE"]))
                 };
 
                 match &*self.lexer.symbol {
@@ -72,15 +76,10 @@ impl MatchPatternRules for PythonCoreParser {
 
                         /* First case must be available */
                         match &*self.lexer.symbol {
-                            Token::NameToken( _ , _ , text, _ ) => {
-                                if text.as_str() == "case" {
-                                    nodes.push(self.parse_case_block()?)
-                                }
-                                else {
-                                    return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting at least one 'case' keyword in 'match' statement!"))))
-                                }
+                            Token::NameToken( _ , _ , text, _ ) if text.as_str() == "case" => {
+                                nodes.push(self.parse_case_block()?)
                             },
-                            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting at least one 'case' keyword in 'match' statement!"))))
+                            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting at least one 'case' keyword in 'match' statement!", &["case"]))
                         }
 
                         /* Optional rest of cases */
@@ -105,39 +104,76 @@ impl MatchPatternRules for PythonCoreParser {
                                 self.lexer.advance();
                                 symbol11
                             },
-                            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting DEDENT in 'match' statement!"))))
+                            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedIndent, "Expecting DEDENT in 'match' statement!", FOLLOW_CASE_BLOCK))
                         };
 
-                        nodes.reverse();
-
                         Ok(Box::new(SyntaxNode::MatchStmtNode(pos, self.lexer.position, symbol1, right, symbol2, symbol3, symbol4, nodes, symbol5)))
                     },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting INDENT in 'match' statement!"))))
+                    _ => Err(self.error_expecting(DiagnosticCode::ExpectedIndent, "Expecting INDENT in 'match' statement!", &["INDENT"]))
                 }
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'match' statement!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedColon, "Expecting ':' in 'match' statement!", FOLLOW_MATCH_SUBJECT))
         }
     }
 
-    fn parse_subject_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn is_match_stmt(&mut self) -> bool {
         match &*self.lexer.symbol {
-            Token::MultiplyToken( _ , _ , _ ) => self.parse_star_expr_named_exp_elements(),
-            _ => self.parse_named_expr()
+            Token::NameToken( _ , _ , text , _ ) if text.as_str() == "match" => {
+                /* 'match' is a soft keyword, only a subject followed by ':' and NEWLINE makes it a statement */
+                match self.lexer.peek(1) {
+                    Token::ColonToken( _ , _ , _ ) |
+                    Token::NewlineToken( _ , _ , _ , _ , _ ) |
+                    Token::AssignToken( _ , _ , _ ) |
+                    Token::PeriodToken( _ , _ , _ ) |
+                    Token::EofToken( _ , _ , _ ) => return false,
+                    _ => ()
+                }
+
+                let saved = self.lexer.clone();
+                self.lexer.advance();
+
+                let is_match = self.parse_subject_expr().is_ok() && match &*self.lexer.symbol {
+                    Token::ColonToken( _ , _ , _ ) => {
+                        self.lexer.advance();
+                        matches!(&*self.lexer.symbol, Token::NewlineToken( _ , _ , _ , _ , _ ))
+                    },
+                    _ => false
+                };
+
+                self.lexer = saved;
+                is_match
+            },
+            _ => false
+        }
+    }
+
+    fn parse_subject_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+        let mut right = self.parse_star_expr_named_exp_elements()?;
+
+        match &mut *right {
+            SyntaxNode::StarExprNamedExprListNode( _ , _ , nodes , separators ) if nodes.len() == 1 && separators.is_empty() => {
+                match &*nodes[0] {
+                    SyntaxNode::StarExprNode( .. ) => Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting ',' after starred 'match' subject!", &[","])),
+                    _ => Ok(nodes.pop().unwrap())
+                }
+            },
+            _ => Ok(right)
         }
     }
 
-    fn parse_case_block(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_case_block(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
 
         let symbol1 = match &*self.lexer.symbol {
             Token::NameToken( s, e, text, t) => {
                 match text.as_str() {
                     "case" => Box::new(Token::CaseToken(*s, *e, t.clone())),
-                    _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'case' keyword in 'case' block!"))))
+                    _ => return Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting 'case' keyword in 'case' block!", &["case"]))
                 }
             },
-            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'case' keyword in 'case' block!"))))
+            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting 'case' keyword in 'case' block!", &["case"]))
         };
+        self.lexer.advance();
 
         let left = self.parse_patterns()?;
 
@@ -146,20 +182,14 @@ impl MatchPatternRules for PythonCoreParser {
             _ => None
         };
 
-        match &*self.lexer.symbol {
-            Token::ColonToken( _ , _ , _ ) => {
-                let symbol2 = self.lexer.symbol.clone();
-                self.lexer.advance();
+        let symbol2 = self.expect_token(Token::ColonToken, "Expecting ':' in 'case' block!");
 
-                let right = self.parse_suite_stmt()?;
+        let right = self.parse_suite_stmt()?;
 
-                Ok(Box::new(SyntaxNode::CaseElementStmtNode(pos, self.lexer.position, symbol1, left, guard, symbol2, right)))
-            },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'case' block!"))))
-        }
+        Ok(Box::new(SyntaxNode::CaseElementStmtNode(pos, self.lexer.position, symbol1, left, guard, symbol2, right)))
     }
 
-    fn parse_guard_expr(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_guard_expr(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
 
         match &*self.lexer.symbol {
@@ -171,18 +201,25 @@ impl MatchPatternRules for PythonCoreParser {
 
                 Ok(Box::new(SyntaxNode::GuardElementStmtNode(pos, self.lexer.position, symbol1, right)))
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'if' keyword in 'case' guard!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting 'if' keyword in 'case' guard!", &["if"]))
         }
     }
 
-    fn parse_patterns(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
-        match &*self.lexer.symbol {
-            Token::MultiplyToken( _ , _ , _ ) => self.parse_open_sequence_pattern(),
-            _ => self.parse_as_pattern()
+    fn parse_patterns(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+        let mut right = self.parse_open_sequence_pattern()?;
+
+        match &mut *right {
+            SyntaxNode::OpenSequencePatternNode( _ , _ , nodes , separators ) if nodes.len() == 1 && separators.is_empty() => {
+                match &*nodes[0] {
+                    SyntaxNode::StarPatternNode( .. ) => Ok(right),
+                    _ => Ok(nodes.pop().unwrap())
+                }
+            },
+            _ => Ok(right)
         }
     }
 
-    fn parse_as_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_as_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let left = self.parse_or_pattern()?;
 
@@ -198,7 +235,7 @@ impl MatchPatternRules for PythonCoreParser {
         }
     }
 
-    fn parse_or_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_or_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -220,15 +257,12 @@ impl MatchPatternRules for PythonCoreParser {
         match separators.len() {
             0 => Ok(left), /* No or '|' patterns found */
             _ => {
-                nodes.reverse();
-                separators.reverse();
-
                 Ok(Box::new(SyntaxNode::MatchOrPatterns(pos, self.lexer.position, left, separators, nodes)))
             }
         }
     }
 
-    fn parse_closed_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_closed_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         match &*self.lexer.symbol {
             Token::FalseToken( _ , _ , _ ) |
@@ -254,7 +288,7 @@ impl MatchPatternRules for PythonCoreParser {
 
                 let left = match &*self.lexer.symbol {
                     Token::NumberToken( _ , _ , _ , _ ) => self.parse_atom_expr()?,
-                    _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting number in closed pattern!"))))
+                    _ => return Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting number in closed pattern!", &["NUMBER"]))
                 };
 
                 match &*self.lexer.symbol {
@@ -265,7 +299,7 @@ impl MatchPatternRules for PythonCoreParser {
 
                         let right = match &*self.lexer.symbol {
                             Token::NumberToken( _ , _ , _ , _ ) => self.parse_atom_expr()?,
-                            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting number in closed pattern!"))))
+                            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting number in closed pattern!", &["NUMBER"]))
                         };
 
                         Ok(Box::new(SyntaxNode::SignedImaginaryNumberNode(pos, self.lexer.position, minus, left, symbol, right)))
@@ -276,11 +310,11 @@ impl MatchPatternRules for PythonCoreParser {
             Token::LeftCurlyBracketToken( _ , _ , _ ) => self.parse_mappings_pattern(),
             Token::LeftParenToken( _ , _ , _ ) |
             Token::LeftSquareBracketToken( _ , _ , _ ) => self.parse_sequence_pattern(),
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting pattern!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting pattern!", FIRST_PATTERN))
         }
     }
 
-    fn parse_wildcard_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_wildcard_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         match &*self.lexer.symbol {
             Token::NameToken( s , e , text, t ) => {
@@ -291,14 +325,14 @@ impl MatchPatternRules for PythonCoreParser {
 
                         Ok(Box::new(SyntaxNode::DefaultPatterNode(pos, self.lexer.position, symbol1)))
                     },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting '_' in wildcard pattern!"))))
+                    _ => Err(self.error_expecting(DiagnosticCode::InvalidPattern, "Expecting '_' in wildcard pattern!", &["_"]))
                 }
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting '_' in wildcard pattern!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::InvalidPattern, "Expecting '_' in wildcard pattern!", &["_"]))
         }
     }
 
-    fn parse_sequence_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_sequence_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         match &*self.lexer.symbol {
             Token::LeftParenToken( _ , _ , _ ) => {
@@ -315,15 +349,9 @@ impl MatchPatternRules for PythonCoreParser {
                     _ => {
                         let right = self.parse_open_sequence_pattern()?;
 
-                        match &*self.lexer.symbol {
-                            Token::RightParenToken( _ , _ , _ ) => {
-                                let symbol2 = self.lexer.symbol.clone();
-                                self.lexer.advance();
+                        let symbol2 = self.expect_token(Token::RightParenToken, "Expecting ')' in sequence pattern!");
 
-                                Ok(Box::new(SyntaxNode::SequenceTuplePatternNode(pos, self.lexer.position, symbol1, Some(right), symbol2)))
-                            },
-                            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ')' in sequence pattern!"))))
-                        }
+                        Ok(Box::new(SyntaxNode::SequenceTuplePatternNode(pos, self.lexer.position, symbol1, Some(right), symbol2)))
                     }
                 }
             },
@@ -341,23 +369,17 @@ impl MatchPatternRules for PythonCoreParser {
                     _ => {
                         let right = self.parse_open_sequence_pattern()?; // Maybee...
 
-                        match &*self.lexer.symbol {
-                            Token::RightSquareBracketToken( _ , _ , _ ) => {
-                                let symbol2 = self.lexer.symbol.clone();
-                                self.lexer.advance();
+                        let symbol2 = self.expect_token(Token::RightSquareBracketToken, "Expecting ']' in sequence pattern!");
 
-                                Ok(Box::new(SyntaxNode::SequenceSquarePatternNode(pos, self.lexer.position, symbol1, Some(right), symbol2)))
-                            },
-                            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ']' in sequence pattern!"))))
-                        }
+                        Ok(Box::new(SyntaxNode::SequenceSquarePatternNode(pos, self.lexer.position, symbol1, Some(right), symbol2)))
                     }
                 }
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting '(' or '[' in sequence pattern!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting '(' or '[' in sequence pattern!", &["(", "["]))
         }
     }
 
-    fn parse_mappings_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_mappings_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -375,7 +397,7 @@ impl MatchPatternRules for PythonCoreParser {
                         Ok(Box::new(SyntaxNode::MappingPatternNode(pos, self.lexer.position, symbol1, Vec::new(), Vec::new(), symbol2)))
                     },
                     Token::PowerToken( _ , _ , _ ) => { /* Single '**' element with optional ',' */
-                        nodes.push(self.parse_power_argument_element()?);
+                        nodes.push(self.parse_power_pattern()?);
 
                         match &*self.lexer.symbol {
                             Token::CommaToken( _ , _ , _ ) => {
@@ -391,7 +413,7 @@ impl MatchPatternRules for PythonCoreParser {
                                 self.lexer.advance();
                                 symbol10
                             },
-                            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting '}' in mappings pattern!"))))
+                            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedClosingBracket, "Expecting '}' in mappings pattern!", FOLLOW_MAPPING_ELEMENT))
                         };
 
                         Ok(Box::new(SyntaxNode::MappingPatternNode(pos, self.lexer.position, symbol1, nodes, separators, symbol2)))
@@ -407,9 +429,9 @@ impl MatchPatternRules for PythonCoreParser {
                                     self.lexer.advance();
 
                                     match &*self.lexer.symbol {
-                                        Token::RightParenToken( _ , _ , _ ) => break,
+                                        Token::RightCurlyBracketToken( _ , _ , _ ) => break,
                                         Token::PowerToken( _ , _ , _ ) => {
-                                            nodes.push(self.parse_power_argument_element()?);
+                                            nodes.push(self.parse_power_pattern()?);
 
                                             match &*self.lexer.symbol {
                                                 Token::CommaToken( _ , _ , _ ) => {
@@ -428,27 +450,24 @@ impl MatchPatternRules for PythonCoreParser {
                             }
                         }
 
-                        nodes.reverse();
-                        separators.reverse();
-
                         let symbol2 = match &*self.lexer.symbol {
                             Token::RightCurlyBracketToken( _ , _ , _ ) => {
                                 let symbol10 = self.lexer.symbol.clone();
                                 self.lexer.advance();
                                 symbol10
                             },
-                            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting '}' in mappings pattern!"))))
+                            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedClosingBracket, "Expecting '}' in mappings pattern!", FOLLOW_MAPPING_ELEMENT))
                         };
 
                         Ok(Box::new(SyntaxNode::MappingPatternNode(pos, self.lexer.position, symbol1, nodes, separators, symbol2)))
                     }
                 }
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting '{' in mappings pattern!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting '{' in mappings pattern!", &["{"]))
         }
     }
 
-    fn parse_key_value_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_key_value_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
 
         let left = match &*self.lexer.symbol {
@@ -471,7 +490,7 @@ impl MatchPatternRules for PythonCoreParser {
 
                 let left = match &*self.lexer.symbol {
                     Token::NumberToken( _ , _ , _ , _ ) => self.parse_atom_expr()?,
-                    _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting number in closed pattern!"))))
+                    _ => return Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting number in closed pattern!", &["NUMBER"]))
                 };
 
                 match &*self.lexer.symbol {
@@ -482,7 +501,7 @@ impl MatchPatternRules for PythonCoreParser {
 
                         let right = match &*self.lexer.symbol {
                             Token::NumberToken( _ , _ , _ , _ ) => self.parse_atom_expr()?,
-                            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting number in closed pattern!"))))
+                            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting number in closed pattern!", &["NUMBER"]))
                         };
 
                         Box::new(SyntaxNode::SignedImaginaryNumberNode(pos, self.lexer.position, minus, left, symbol, right))
@@ -490,7 +509,7 @@ impl MatchPatternRules for PythonCoreParser {
                     _ => Box::new(SyntaxNode::SignedNumberNode(pos, self.lexer.position, minus, left))
                 }
             },
-            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'key' pattern in mappings pattern!"))))
+            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting 'key' pattern in mappings pattern!", FIRST_MAPPING_KEY))
         };
 
         let symbol = match &*self.lexer.symbol {
@@ -499,7 +518,7 @@ impl MatchPatternRules for PythonCoreParser {
                 self.lexer.advance();
                 symbol10
             },
-            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in mappings pattern!"))))
+            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedColon, "Expecting ':' in mappings pattern!", &[":"]))
         };
 
         let right = self.parse_as_pattern()?;
@@ -507,7 +526,7 @@ impl MatchPatternRules for PythonCoreParser {
         Ok(Box::new(SyntaxNode::KeyValuePatternNode(pos, self.lexer.position, left, symbol, right)))
     }
 
-    fn parse_attr_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_attr_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -524,23 +543,20 @@ impl MatchPatternRules for PythonCoreParser {
 
                             match &*self.lexer.symbol {
                                 Token::NameToken( _ , _ , _ , _ ) => nodes.push(self.parse_atom_expr()?),
-                                _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'name' in 'key' pattern in mappings pattern!"))))
+                                _ => return Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting 'name' in 'key' pattern in mappings pattern!", &["NAME"]))
                             }
                         },
                         _ => break
                     }
                 }
 
-                nodes.reverse();
-                separators.reverse();
-
                 Ok(Box::new(SyntaxNode::NameAttributeNode(pos, self.lexer.position, nodes, separators)))
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME in 'key' pattern in mappings pattern!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME in 'key' pattern in mappings pattern!", &["NAME"]))
         }
     }
 
-    fn parse_power_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_power_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         match &*self.lexer.symbol {
             Token::PowerToken( _ , _ , _ ) => {
@@ -551,20 +567,20 @@ impl MatchPatternRules for PythonCoreParser {
 
                 Ok(Box::new(SyntaxNode::DoubleStarPatterNode(pos, self.lexer.position, symbol1, right)))
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting '**' in power pattern!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting '**' in power pattern!", &["**"]))
         }
     }
 
 
-    fn parse_class_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_class_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
 
         match &*self.lexer.symbol {
             Token::NameToken( _ , _ , text , _ ) => {
-                match &*text.as_str() {
-                    "_" => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Not expecting '_' in class name")))),
+                match text.as_str() {
+                    "_" => return Err(self.error(DiagnosticCode::InvalidPattern, "Not expecting '_' in class name")),
                     _ => {
                         nodes.push(self.parse_atom_expr()?);
 
@@ -576,7 +592,7 @@ impl MatchPatternRules for PythonCoreParser {
 
                                     match &*self.lexer.symbol {
                                         Token::NameToken( _ , _ , _ , _ ) => nodes.push(self.parse_atom_expr()?),
-                                        _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME after '.' in class name!"))))
+                                        _ => return Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME after '.' in class name!", &["NAME"]))
                                     }
                                 },
                                 _ => break
@@ -593,54 +609,86 @@ impl MatchPatternRules for PythonCoreParser {
                                         let symbol2 = self.lexer.symbol.clone();
                                         self.lexer.advance();
 
-                                        nodes.reverse();
-                                        separators.reverse();
-
                                         Ok(Box::new(SyntaxNode::ClassPatternNode(pos, self.lexer.position, nodes, separators, symbol1, Vec::new(), Vec::new(), symbol2)))
                                     },
                                     _ => {
+                                        let mut arguments = Vec::<Box<SyntaxNode>>::new();
+                                        let mut commas = Vec::<Box<Token>>::new();
+                                        let mut has_keyword = false;
+
+                                        /* Positional patterns first, then keyword patterns as 'x=0' */
+                                        loop {
+                                            let pos2 = self.lexer.position;
+                                            let is_keyword = matches!(&*self.lexer.symbol, Token::NameToken( _ , _ , _ , _ )) && matches!(self.lexer.peek(1), Token::AssignToken( _ , _ , _ ));
+
+                                            match is_keyword {
+                                                true => {
+                                                    let symbol3 = self.lexer.symbol.clone();
+                                                    self.lexer.advance();
+                                                    let symbol4 = self.lexer.symbol.clone();
+                                                    self.lexer.advance();
+
+                                                    let right = self.parse_as_pattern()?;
+                                                    has_keyword = true;
+
+                                                    arguments.push(Box::new(SyntaxNode::KeywordPatternNode(pos2, self.lexer.position, symbol3, symbol4, right)))
+                                                },
+                                                _ if has_keyword => return Err(self.error(DiagnosticCode::InvalidPattern, "positional patterns follow keyword patterns")),
+                                                _ => arguments.push(self.parse_as_pattern()?)
+                                            }
+
+                                            match &*self.lexer.symbol {
+                                                Token::CommaToken( _ , _ , _ ) => {
+                                                    commas.push(self.lexer.symbol.clone());
+                                                    self.lexer.advance();
+
+                                                    if let Token::RightParenToken( _ , _ , _ ) = &*self.lexer.symbol {
+                                                        break
+                                                    }
+                                                },
+                                                _ => break
+                                            }
+                                        }
 
-                                        return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("FIX!"))))
+                                        let symbol2 = self.expect_token(Token::RightParenToken, "Expecting ')' in class pattern!");
+
+                                        Ok(Box::new(SyntaxNode::ClassPatternNode(pos, self.lexer.position, nodes, separators, symbol1, arguments, commas, symbol2)))
                                     }
                                 }
                             },
                             _ => {
-                                nodes.reverse();
-                                separators.reverse();
-
                                 Ok(Box::new(SyntaxNode::NameAttributeNode(pos, self.lexer.position, nodes, separators)))
                             }
                         }
                     }
                 }
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting name in class pattern!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting name in class pattern!", &["NAME"]))
         }
     }
 
-    fn parse_capture_target(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
-        let pos = self.lexer.position;
+    fn parse_capture_target(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         match &*self.lexer.symbol {
             Token::NameToken( _ , _ , text, _ ) => {
                 match text.as_str() {
-                    "_" => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Cannot capture '_' in 'as' pattern!")))),
+                    "_" => Err(self.error(DiagnosticCode::InvalidPattern, "Cannot capture '_' in 'as' pattern!")),
                     _ => {
                         let right = self.parse_atom_expr()?;
 
                         match &*self.lexer.symbol {
                             Token::PeriodToken( _ , _ , _ ) |
                             Token::LeftParenToken( _ , _ , _ ) |
-                            Token::AssignToken( _ , _ , _ ) => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Cannot have '.', '(' or '=' after NAME in 'as' pattern!")))),
+                            Token::AssignToken( _ , _ , _ ) => Err(self.error(DiagnosticCode::InvalidPattern, "Cannot have '.', '(' or '=' after NAME in 'as' pattern!")),
                             _ => Ok(right)
                         }
                     }
                 }
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting variable name in 'as' pattern!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting variable name in 'as' pattern!", &["NAME"]))
         }
     }
 
-    fn parse_open_sequence_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_open_sequence_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -655,7 +703,9 @@ impl MatchPatternRules for PythonCoreParser {
 
                     match &*self.lexer.symbol {
                         Token::IfToken( _ , _ , _ ) |
-                        Token::ColonToken( _ , _ , _ ) => break,
+                        Token::ColonToken( _ , _ , _ ) |
+                        Token::RightParenToken( _ , _ , _ ) |
+                        Token::RightSquareBracketToken( _ , _ , _ ) => break,
                         _ => nodes.push(match &*self.lexer.symbol { Token::MultiplyToken( _ , _ , _ ) => self.parse_star_pattern()?, _ => self.parse_as_pattern()?})
                     }
                 },
@@ -663,13 +713,10 @@ impl MatchPatternRules for PythonCoreParser {
             }
         }
 
-        nodes.reverse();
-        separators.reverse();
-
         Ok(Box::new(SyntaxNode::OpenSequencePatternNode(pos, self.lexer.position, nodes, separators)))
     }
 
-    fn parse_star_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_star_pattern(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
 
         match &*self.lexer.symbol {
@@ -695,11 +742,11 @@ impl MatchPatternRules for PythonCoreParser {
                             }
                         }
                     },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting variable name in '*' pattern!"))))
+                    _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting variable name in '*' pattern!", &["NAME"]))
                 }
 
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting '*' in '*' pattern!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting '*' in '*' pattern!", &["*"]))
         }
     }
 }
@@ -707,8 +754,182 @@ impl MatchPatternRules for PythonCoreParser {
 // Unittests for match grammar rules ///////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use crate::parser::parser_options::ParserOptions;
+    use crate::parser::python_core_parser::PythonCoreParser;
+    use crate::parser::python_core_statement_parser::StatementRules;
+    use crate::parser::python_version::{Feature, PythonVersion};
+    use crate::parser::syntax_nodes::SyntaxNode;
+    use crate::parser::token_nodes::Token;
+
     #[test]
     fn dummy_test() {
         assert_eq!(1 == 1, true);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn parse_match_as_name() {
+        let mut parser = PythonCoreParser::new("match = re.match(p, s)\n");
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::SimpleStmtNode( _ , _ , nodes , _ , _ ) => {
+                match &*nodes[0] {
+                    SyntaxNode::AssignmentStmtNode( _ , _ , left , right , None ) => {
+                        assert!(matches!(&**left, SyntaxNode::TestListStarExprStmtNode( _ , _ , names , _ ) if matches!(&*names[0], SyntaxNode::NameExprNode(0, _ , symbol) if matches!(&**symbol, Token::NameToken(0, 5, text, _ ) if text.as_str() == "match"))));
+                        assert_eq!(right.len(), 1)
+                    },
+                    _ => panic!("Expecting assignment statement!")
+                }
+            },
+            _ => panic!("Expecting simple statement!")
+        }
+
+        let mut parser = PythonCoreParser::new("match(x)\n");
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::SimpleStmtNode( _ , _ , nodes , _ , _ ) => {
+                assert!(matches!(&*nodes[0], SyntaxNode::TestListStarExprStmtNode( _ , _ , elements , _ ) if matches!(&*elements[0], SyntaxNode::AtomExprNode( _ , _ , None , _ , trailers ) if matches!(&*trailers[0], SyntaxNode::TrailerCallExprNode( .. )))))
+            },
+            _ => panic!("Expecting simple statement!")
+        }
+    }
+
+    #[test]
+    fn parse_soft_keywords_as_names() {
+        let sources = [
+            "match.group(1)\n",
+            "match[0] = 1\n",
+            "match * 2\n",
+            "match: int = 1\n",
+            "print(match, case, _)\n",
+            "case = 1\n",
+            "case(x)\n",
+            "_ = f()\n",
+            "type = 1\n",
+            "type(x)\n",
+        ];
+
+        for source in sources {
+            let mut parser = PythonCoreParser::new(source);
+            let node = parser.parse_stmt();
+            assert!(matches!(node.as_deref(), Ok(SyntaxNode::SimpleStmtNode( .. ))), "{}", source);
+            assert!(matches!(&*parser.lexer.symbol, Token::EofToken( .. )), "{}", source)
+        }
+    }
+
+    #[test]
+    fn parse_match_statement() {
+        let mut parser = PythonCoreParser::new("match command.split():\n    case [action, obj]:\n        pass\n    case _:\n        match = 1\n");
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::MatchStmtNode( _ , _ , symbol1 , _ , _ , _ , _ , cases , _ ) => {
+                assert!(matches!(&**symbol1, Token::MatchToken(0, 5, _ )));
+                assert_eq!(cases.len(), 2);
+
+                match &*cases[0] {
+                    SyntaxNode::CaseElementStmtNode( _ , _ , symbol2 , pattern , None , _ , _ ) => {
+                        assert!(matches!(&**symbol2, Token::CaseToken(27, 31, _ )));
+                        assert!(matches!(&**pattern, SyntaxNode::SequenceSquarePatternNode( .. )))
+                    },
+                    _ => panic!("Expecting case block!")
+                }
+
+                match &*cases[1] {
+                    SyntaxNode::CaseElementStmtNode( _ , _ , _ , pattern , None , _ , _ ) => {
+                        assert!(matches!(&**pattern, SyntaxNode::DefaultPatterNode( _ , _ , symbol3 ) if matches!(&**symbol3, Token::DefaultToken( .. ))))
+                    },
+                    _ => panic!("Expecting case block!")
+                }
+            },
+            _ => panic!("Expecting match statement!")
+        }
+    }
+
+    #[test]
+    fn parse_match_statement_with_ambiguous_subject() {
+        let sources = [
+            "match (x):\n    case 1 | 2:\n        pass\n",
+            "match [a, b]:\n    case [1, *rest]:\n        pass\n",
+            "match -x:\n    case -1:\n        pass\n",
+            "match x, *rest:\n    case 1, 2:\n        pass\n",
+            "match match:\n    case case:\n        pass\n",
+        ];
+
+        for source in sources {
+            let mut parser = PythonCoreParser::new(source);
+            let node = parser.parse_stmt();
+            assert!(matches!(node.as_deref(), Ok(SyntaxNode::MatchStmtNode( .. ))), "{}", source);
+        }
+    }
+
+    #[test]
+    fn parse_match_statement_for_older_target() {
+        let options = ParserOptions { target: PythonVersion::Python39, ..ParserOptions::default() };
+        let mut parser = PythonCoreParser::with_options("match x:\n    case 1:\n        pass\n", &options);
+
+        assert!(matches!(&*parser.parse_stmt().unwrap(), SyntaxNode::MatchStmtNode( .. )));
+        assert_eq!(parser.lexer.errors.len(), 1);
+        assert_eq!(parser.lexer.errors[0].message, "pattern matching is only supported in Python 3.10 and greater, target is Python 3.9");
+
+        let options = ParserOptions { target: PythonVersion::Python39, features: vec![(Feature::PatternMatching, true)], ..ParserOptions::default() };
+        let mut parser = PythonCoreParser::with_options("match x:\n    case 1:\n        pass\n", &options);
+
+        assert!(parser.parse_stmt().is_ok());
+        assert!(parser.lexer.errors.is_empty())
+    }
+
+    /// Pattern of the only case in a match statement, starting at offset 18.
+    fn parse_case_pattern(pattern: &str) -> Result<Box<SyntaxNode>, String> {
+        let mut parser = PythonCoreParser::new(&format!("match x:\n    case {}:\n        pass\n", pattern));
+        let node = parser.parse_stmt().map_err(|error| error.message)?;
+
+        match *node {
+            SyntaxNode::MatchStmtNode( _ , _ , _ , _ , _ , _ , _ , mut cases , _ ) => match *cases.remove(0) {
+                SyntaxNode::CaseElementStmtNode( _ , _ , _ , pattern , _ , _ , _ ) => Ok(pattern),
+                _ => Err(String::from("Expecting case block!"))
+            },
+            _ => Err(String::from("Expecting match statement!"))
+        }
+    }
+
+    #[test]
+    fn parse_sequence_pattern_with_star() {
+        let pattern = parse_case_pattern("[1, *rest]").unwrap();
+        assert!(matches!(&*pattern, SyntaxNode::SequenceSquarePatternNode(18, 28, _ , Some(right) , _ )
+            if matches!(&**right, SyntaxNode::OpenSequencePatternNode( _ , _ , nodes , _ ) if nodes.len() == 2
+                && matches!(&*nodes[0], SyntaxNode::SignedNumberNode(19, _ , None , _ )) && matches!(&*nodes[1], SyntaxNode::StarPatternNode(22, _ , _ , _ )))));
+
+        let pattern = parse_case_pattern("(*_, last)").unwrap();
+        assert!(matches!(&*pattern, SyntaxNode::SequenceTuplePatternNode( _ , _ , _ , Some(right) , _ )
+            if matches!(&**right, SyntaxNode::OpenSequencePatternNode( _ , _ , nodes , _ ) if matches!(&*nodes[0], SyntaxNode::StarPatternNode( _ , _ , _ , wildcard ) if matches!(&**wildcard, SyntaxNode::DefaultPatterNode( .. ))))));
+    }
+
+    #[test]
+    fn parse_mapping_pattern_with_double_star() {
+        let pattern = parse_case_pattern("{'a': 1, **kw}").unwrap();
+        assert!(matches!(&*pattern, SyntaxNode::MappingPatternNode(18, 32, _ , nodes , separators , _ ) if nodes.len() == 2 && separators.len() == 1
+            && matches!(&*nodes[0], SyntaxNode::KeyValuePatternNode( .. )) && matches!(&*nodes[1], SyntaxNode::DoubleStarPatterNode(27, _ , _ , _ ))));
+
+        let pattern = parse_case_pattern("{**kw}").unwrap();
+        assert!(matches!(&*pattern, SyntaxNode::MappingPatternNode( _ , _ , _ , nodes , _ , _ ) if nodes.len() == 1));
+    }
+
+    #[test]
+    fn parse_class_pattern_with_arguments() {
+        let pattern = parse_case_pattern("Point(x=0, y=_)").unwrap();
+        assert!(matches!(&*pattern, SyntaxNode::ClassPatternNode(18, 33, names , _ , _ , arguments , commas , _ ) if names.len() == 1 && arguments.len() == 2 && commas.len() == 1
+            && matches!(&*arguments[0], SyntaxNode::KeywordPatternNode(24, _ , name , _ , right ) if name.text() == "x" && matches!(&**right, SyntaxNode::SignedNumberNode( .. )))
+            && matches!(&*arguments[1], SyntaxNode::KeywordPatternNode( _ , _ , _ , _ , right ) if matches!(&**right, SyntaxNode::DefaultPatterNode( .. )))));
+
+        let pattern = parse_case_pattern("geo.Point(1, 2, z=[3],)").unwrap();
+        assert!(matches!(&*pattern, SyntaxNode::ClassPatternNode( _ , _ , names , _ , _ , arguments , commas , _ ) if names.len() == 2 && arguments.len() == 3 && commas.len() == 3
+            && matches!(&*arguments[0], SyntaxNode::SignedNumberNode( .. )) && matches!(&*arguments[2], SyntaxNode::KeywordPatternNode( .. ))));
+
+        let pattern = parse_case_pattern("Point(x=0) | Point(1 | 2 as y)").unwrap();
+        assert!(matches!(&*pattern, SyntaxNode::MatchOrPatterns( _ , _ , left , _ , _ ) if matches!(&**left, SyntaxNode::ClassPatternNode( .. ))));
+
+        assert_eq!(parse_case_pattern("Point(x=0, 1)").err().unwrap(), "positional patterns follow keyword patterns");
+    }
+}
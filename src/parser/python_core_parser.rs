@@ -1,14 +1,217 @@
-
+use crate::parser::parser_options::ParserOptions;
+use crate::parser::python_core_statement_parser::StatementRules;
+use crate::parser::python_core_match_parser::MatchPatternRules;
 use crate::parser::python_core_tokenizer::{LexerMethods, PythonCoreTokenizer};
+use crate::parser::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::parser::syntax_nodes::SyntaxNode;
+use crate::parser::token_nodes::Token;
+use crate::parser::trivia_nodes::Trivia;
 
 
 pub struct PythonCoreParser
 {
-    pub lexer: Box<PythonCoreTokenizer>
+    pub lexer: Box<PythonCoreTokenizer>,
+    /// Reading the tokens of a statement with an error again, where the parser reports nothing more.
+    pub(crate) recovering: bool,
+    /// Number of missing tokens made up by expect_token, also counted while nothing is reported.
+    pub(crate) missing_tokens: usize
 }
 
 impl PythonCoreParser {
-    pub(crate) fn new() -> PythonCoreParser { PythonCoreParser{
-        lexer: Box::new(PythonCoreTokenizer::new())
-    } }
+    #[cfg(test)]
+    pub(crate) fn new(source: &str) -> PythonCoreParser {
+        PythonCoreParser::with_options(source, &ParserOptions::default())
+    }
+
+    pub(crate) fn with_options(source: &str, options: &ParserOptions) -> PythonCoreParser {
+        let mut lexer = Box::new(PythonCoreTokenizer::with_options(source, options));
+        lexer.advance();
+
+        PythonCoreParser::from_lexer(lexer)
+    }
+
+    pub(crate) fn from_bytes(source: &[u8], options: &ParserOptions) -> PythonCoreParser {
+        let mut lexer = Box::new(PythonCoreTokenizer::from_bytes(source, options));
+        lexer.advance();
+
+        PythonCoreParser::from_lexer(lexer)
+    }
+
+    /// Parser reading from a tokenizer already at its first token.
+    pub(crate) fn from_lexer(lexer: Box<PythonCoreTokenizer>) -> PythonCoreParser {
+        PythonCoreParser {
+            lexer,
+            recovering: false,
+            missing_tokens: 0
+        }
+    }
+
+    /// Error about the current token.
+    pub(crate) fn error(&self, code: DiagnosticCode, message: &str) -> Box<Diagnostic> {
+        let (start, end) = self.lexer.symbol.span();
+        Box::new(Diagnostic::error(code, start, end, String::from(message)))
+    }
+
+    /// Error about the current token, with the tokens that would have been accepted in its place.
+    pub(crate) fn error_expecting(&self, code: DiagnosticCode, message: &str, expected: &[&str]) -> Box<Diagnostic> {
+        Box::new((*self.error(code, message)).with_expected(expected))
+    }
+
+    /// Reports an error the parser goes on from, unless reading a statement with an error again.
+    pub(crate) fn report(&mut self, error: Box<Diagnostic>) {
+        if !self.recovering {
+            self.lexer.errors.push(error)
+        }
+    }
+
+    /// Consumes the expected token, or reports it and returns it as a missing token with no width.
+    /// The report comes with a fix inserting the token.
+    pub(crate) fn expect_token(&mut self, kind: fn(u32, u32, Vec<Box<Trivia>>) -> Token, message: &str) -> Box<Token> {
+        let position = self.lexer.position;
+        let missing = Box::new(kind(position, position, Vec::new()));
+
+        match std::mem::discriminant(&*self.lexer.symbol) == std::mem::discriminant(&*missing) {
+            true => {
+                let symbol = self.lexer.symbol.clone();
+                self.lexer.advance();
+                symbol
+            },
+            _ => {
+                let text = kind(0, 1, Vec::new()).text();
+                let code = match &*missing {
+                    Token::ColonToken( _ , _ , _ ) => DiagnosticCode::ExpectedColon,
+                    Token::RightParenToken( _ , _ , _ ) |
+                    Token::RightSquareBracketToken( _ , _ , _ ) |
+                    Token::RightCurlyBracketToken( _ , _ , _ ) => DiagnosticCode::ExpectedClosingBracket,
+                    _ => DiagnosticCode::ExpectedToken
+                };
+                let error = Box::new((*self.error_expecting(code, message, &[&text])).with_fix(position, position, &text, &format!("Insert '{}'", text)));
+                self.report(error);
+                self.missing_tokens += 1;
+                missing
+            }
+        }
+    }
+
+    /// Statement in a block or file. On a syntax error the statement is read again into an error
+    /// node. Only the first parser error in the statement's own tokens is kept, as later ones mostly
+    /// follow from it, while statements in its blocks report their errors as usual.
+    pub(crate) fn parse_stmt_or_recover(&mut self) -> Box<SyntaxNode> {
+        let saved = self.lexer.clone();
+        let recovering = std::mem::replace(&mut self.recovering, false);
+
+        let node = match self.parse_stmt() {
+            Ok(node) => node,
+            Err(error) => {
+                let attempt = std::mem::replace(&mut self.lexer, saved).errors;
+                let count = self.lexer.errors.len();
+
+                let node = self.parse_error_stmt();
+
+                /* Errors of the attempt not found again while reading the error node are from the parser */
+                let errors = &self.lexer.errors[count..];
+                let first = attempt[count..].iter()
+                    .find(|e| !errors.iter().any(|l| l.start == e.start && l.message == e.message))
+                    .cloned()
+                    .unwrap_or(error);
+
+                /* Kept ahead of the errors reported later in the statement's blocks */
+                let index = errors.iter().position(|e| e.start > first.start).map_or(self.lexer.errors.len(), |i| count + i);
+                self.lexer.errors.insert(index, first);
+
+                node
+            }
+        };
+
+        self.recovering = recovering;
+        node
+    }
+
+    /// Error node for a statement that failed to parse, up to and including its NEWLINE and any
+    /// indented block after it, and the 'elif', 'else', 'except' and 'finally' clauses continuing it.
+    /// Expressions parsing without errors or missing tokens and the statements of the blocks become
+    /// child nodes, all other tokens are kept as skipped tokens. Nothing is reported for the
+    /// statement's own tokens, and the 'case' blocks of a 'match' are read as 'case' blocks.
+    fn parse_error_stmt(&mut self) -> Box<SyntaxNode> {
+        let pos = self.lexer.position;
+        let mut nodes = Vec::<Box<SyntaxNode>>::new();
+        let mut symbols = Vec::<Box<Token>>::new();
+        let is_match = self.is_match_stmt();
+
+        self.recovering = true;
+
+        loop {
+            match &*self.lexer.symbol {
+                Token::EofToken( _ , _ , _ ) |
+                Token::DedentToken( _ , _ , _ ) => break,
+                Token::NewlineToken( _ , _ , _ , _ , _ ) => {
+                    symbols.push(self.lexer.symbol.clone());
+                    self.lexer.advance();
+
+                    if !matches!(&*self.lexer.symbol, Token::IndentToken( _ , _ , _ )) && !self.is_continuation_clause() {
+                        break
+                    }
+                },
+                Token::IndentToken( _ , _ , _ ) => {
+                    symbols.push(self.lexer.symbol.clone());
+                    self.lexer.advance();
+
+                    loop {
+                        match &*self.lexer.symbol {
+                            Token::EofToken( _ , _ , _ ) => break,
+                            Token::DedentToken( _ , _ , _ ) => {
+                                symbols.push(self.lexer.symbol.clone());
+                                self.lexer.advance();
+                                break
+                            },
+                            Token::NameToken( _ , _ , text , _ ) if is_match && text.as_str() == "case" => nodes.push(self.parse_case_or_recover()),
+                            _ => nodes.push(self.parse_stmt_or_recover())
+                        }
+                    }
+
+                    if !self.is_continuation_clause() {
+                        break
+                    }
+                },
+                _ => {
+                    let saved = self.lexer.clone();
+                    let count = self.lexer.errors.len();
+                    let missing = self.missing_tokens;
+
+                    match self.parse_test_list_star_expr_stmt() {
+                        Ok(node) if self.lexer.errors.len() == count && self.missing_tokens == missing => nodes.push(node),
+                        _ => {
+                            self.lexer = saved;
+                            symbols.push(self.lexer.symbol.clone());
+                            self.lexer.advance()
+                        }
+                    }
+                }
+            }
+        }
+
+        Box::new(SyntaxNode::ErrorNode(pos, self.lexer.position, nodes, symbols))
+    }
+
+    /// 'case' block in the error node of a 'match', or an error node of its own when its pattern fails.
+    fn parse_case_or_recover(&mut self) -> Box<SyntaxNode> {
+        let saved = self.lexer.clone();
+        let recovering = std::mem::replace(&mut self.recovering, false);
+
+        let node = match self.parse_case_block() {
+            Ok(node) => node,
+            Err( _ ) => {
+                self.lexer = saved;
+                self.parse_error_stmt()
+            }
+        };
+
+        self.recovering = recovering;
+        node
+    }
+
+    /// Whether the current token starts a clause continuing the compound statement before it.
+    fn is_continuation_clause(&self) -> bool {
+        matches!(&*self.lexer.symbol, Token::ElifToken( _ , _ , _ ) | Token::ElseToken( _ , _ , _ ) | Token::ExceptToken( _ , _ , _ ) | Token::FinallyToken( _ , _ , _ ))
+    }
 }
@@ -1,7 +1,9 @@
 
 use crate::parser::python_core_tokenizer::LexerMethods;
+use crate::parser::python_version::Feature;
 use crate::parser::python_core_expression_parser::ExpressionRules;
-use crate::parser::syntax_error::SyntaxError;
+use crate::parser::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::parser::expected_tokens::{FIRST_EXPRESSION, FIRST_EXPRESSION_STMT, FIRST_STAR_EXPRESSION, FOLLOW_ASYNC, FOLLOW_FROM, FOLLOW_SMALL_STMT, FOLLOW_TRY_BODY};
 use crate::parser::syntax_nodes::SyntaxNode;
 use crate::parser::token_nodes::Token;
 use crate::parser::python_core_block_parser::BlockGrammarRules;
@@ -10,47 +12,50 @@ use super::python_core_parser::PythonCoreParser;
 
 
 pub trait StatementRules {
-    fn parse_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_simple_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_small_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_expr_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_test_list_star_expr_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_del_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_pass_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_flow_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_break_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_continue_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_return_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_raise_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_import_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_import_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_import_from_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_import_as_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_dotted_as_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_import_as_names_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_dotted_as_names_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_dotted_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_global_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_nonlocal_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_assert_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_compound_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_async_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_if_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_elif_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_else_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_while_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_for_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_try_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_except_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_with_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_with_item_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_except_clause_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
-    fn parse_suite_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>>;
+    fn parse_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_simple_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_small_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_expr_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_test_list_star_expr_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_del_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_pass_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_flow_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_break_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_continue_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_return_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_raise_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_import_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_import_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_import_from_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_import_as_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_dotted_as_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_import_as_names_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_dotted_as_names_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_dotted_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_global_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_nonlocal_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_assert_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn is_type_alias_stmt(&mut self) -> bool;
+    fn parse_type_alias_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_compound_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_async_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_if_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_elif_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_else_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_while_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_for_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_try_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_except_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_with_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_with_item_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn is_parenthesized_with_items(&mut self) -> bool;
+    fn parse_except_clause_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
+    fn parse_suite_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>>;
 
 }
 
 impl StatementRules for PythonCoreParser {
-    fn parse_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         match &*self.lexer.symbol {
             Token::IfToken( _ , _ , _ ) |
             Token::WhileToken( _ , _ , _ ) |
@@ -61,9 +66,9 @@ impl StatementRules for PythonCoreParser {
             Token::MatricesToken( _ , _ , _ ) |
             Token::DefToken( _ , _ , _ ) |
             Token::ClassToken( _ , _ , _ ) => self.parse_compound_stmt(),
-            Token::NameToken( _ , _ , kw , _ ) => {
-                match &*kw.as_str() {
-                    "match" => self.parse_match_stmt(),
+            Token::NameToken( _ , _ , _ , _ ) => {
+                match self.is_match_stmt() {
+                    true => self.parse_match_stmt(),
                     _ => self.parse_simple_stmt()
                 }
             },
@@ -71,7 +76,7 @@ impl StatementRules for PythonCoreParser {
         }
     }
 
-    fn parse_simple_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_simple_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -98,16 +103,17 @@ impl StatementRules for PythonCoreParser {
                 let symbol = self.lexer.symbol.clone();
                 self.lexer.advance();
 
-                nodes.reverse();
-                separators.reverse();
-
                 Ok(Box::new(SyntaxNode::SimpleStmtNode(pos, self.lexer.position, nodes, separators, symbol)))
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NEWLINE in statement list!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedNewline, "Expecting NEWLINE in statement list!", FOLLOW_SMALL_STMT))
         }
     }
 
-    fn parse_small_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_small_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+        if self.is_type_alias_stmt() {
+            return self.parse_type_alias_stmt()
+        }
+
         match &*self.lexer.symbol {
             Token::DelToken( _ , _ , _ ) => self.parse_del_stmt(),
             Token::PassToken( _ , _ , _ ) => self.parse_pass_stmt(),
@@ -125,9 +131,14 @@ impl StatementRules for PythonCoreParser {
         }
     }
 
-    fn parse_expr_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_expr_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
-        let mut left = self.parse_test_list_star_expr_stmt()?;
+
+        /* Any other token is reported with all tokens that can start an expression statement */
+        if !self.is_test_expr_start() && !matches!(&*self.lexer.symbol, Token::MultiplyToken( _ , _ , _ )) {
+            return Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting valid literal!", FIRST_EXPRESSION_STMT))
+        }
+        let left = self.parse_test_list_star_expr_stmt()?;
 
         match &*self.lexer.symbol {
             Token::PlusAssignToken( _ , _ , _ ) |
@@ -180,6 +191,8 @@ impl StatementRules for PythonCoreParser {
 
                         let next = match &*self.lexer.symbol {
                             Token::YieldToken( _ , _ , _ ) => self.parse_yield_expr()?,
+                            Token::MultiplyToken( _ , _ , _ ) => self.parse_test_list_star_expr_stmt()?,
+                            _ if !self.is_test_expr_start() => return Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting valid literal!", FIRST_EXPRESSION_STMT)),
                             _ => self.parse_test_list_star_expr_stmt()?
                         };
 
@@ -196,6 +209,8 @@ impl StatementRules for PythonCoreParser {
 
                 let right = match &*self.lexer.symbol {
                     Token::YieldToken( _ , _ , _ ) => self.parse_yield_expr()?,
+                    Token::MultiplyToken( _ , _ , _ ) => self.parse_test_list_star_expr_stmt()?,
+                    _ if !self.is_test_expr_start() => return Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting valid literal!", FIRST_EXPRESSION_STMT)),
                     _ => self.parse_test_list_star_expr_stmt()?
                 };
 
@@ -203,12 +218,14 @@ impl StatementRules for PythonCoreParser {
 
                 loop {
                     match &*self.lexer.symbol {
-                        Token::AssertToken( _ , _ , _ ) => {
+                        Token::AssignToken( _ , _ , _ ) => {
                             symbol = self.lexer.symbol.clone();
                             self.lexer.advance();
 
                             let right = match &*self.lexer.symbol {
                                 Token::YieldToken( _ , _ , _ ) => self.parse_yield_expr()?,
+                                Token::MultiplyToken( _ , _ , _ ) => self.parse_test_list_star_expr_stmt()?,
+                                _ if !self.is_test_expr_start() => return Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting valid literal!", FIRST_EXPRESSION_STMT)),
                                 _ => self.parse_test_list_star_expr_stmt()?
                             };
 
@@ -227,21 +244,20 @@ impl StatementRules for PythonCoreParser {
                     _ => None
                 };
 
-                nodes.reverse();
-
                 Ok(Box::new(SyntaxNode::AssignmentStmtNode(pos, self.lexer.position, left, nodes, tc)))
             },
             _ => Ok(left)
         }
     }
 
-    fn parse_test_list_star_expr_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_test_list_star_expr_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes: Vec<Box<SyntaxNode>> = Vec::new();
         let mut separators: Vec<Box<Token>> = Vec::new();
 
         nodes.push(match &*self.lexer.symbol {
             Token::MultiplyToken( _ , _ , _ ) => self.parse_star_expr()?,
+            _ if !self.is_test_expr_start() => return Err(self.error_expecting(DiagnosticCode::ExpectedExpression, "Expecting valid literal!", FIRST_STAR_EXPRESSION)),
             _ => self.parse_test_expr()?
         });
 
@@ -279,13 +295,10 @@ impl StatementRules for PythonCoreParser {
             }
         }
 
-        nodes.reverse();
-        separators.reverse();
-
         Ok(Box::new(SyntaxNode::TestListStarExprStmtNode(pos, self.lexer.position, nodes, separators)))
     }
 
-    fn parse_del_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_del_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -295,7 +308,7 @@ impl StatementRules for PythonCoreParser {
         Ok(Box::new(SyntaxNode::DelStmtNode(pos, self.lexer.position, symbol, right)))
     }
 
-    fn parse_pass_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_pass_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -303,9 +316,7 @@ impl StatementRules for PythonCoreParser {
         Ok(Box::new(SyntaxNode::PassStmtNode(pos, self.lexer.position, symbol)))
     }
 
-    fn parse_flow_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
-        let pos = self.lexer.position;
-
+    fn parse_flow_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let right = match &*self.lexer.symbol {
             Token::BreakToken( _ , _ , _ ) => self.parse_break_stmt()?,
             Token::ContinueToken( _ , _ , _ ) => self.parse_continue_stmt()?,
@@ -317,7 +328,7 @@ impl StatementRules for PythonCoreParser {
         Ok(right)
     }
 
-    fn parse_break_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_break_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -325,7 +336,7 @@ impl StatementRules for PythonCoreParser {
         Ok(Box::new(SyntaxNode::BreakStmtNode(pos, self.lexer.position, symbol)))
     }
 
-    fn parse_continue_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_continue_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -333,7 +344,7 @@ impl StatementRules for PythonCoreParser {
         Ok(Box::new(SyntaxNode::ContinueStmtNode(pos, self.lexer.position, symbol)))
     }
 
-    fn parse_return_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_return_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -347,7 +358,7 @@ impl StatementRules for PythonCoreParser {
         Ok(Box::new(SyntaxNode::ReturnStmtNode(pos, self.lexer.position, symbol, right)))
     }
 
-    fn parse_raise_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_raise_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -375,14 +386,14 @@ impl StatementRules for PythonCoreParser {
         }
     }
 
-    fn parse_import_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_import_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         match &*self.lexer.symbol {
-            Token::ImportToken( _ , _ , _ ) => self.parse_import_stmt(),
+            Token::ImportToken( _ , _ , _ ) => self.parse_import_name_stmt(),
             _ => self.parse_import_from_stmt()
         }
     }
 
-    fn parse_import_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_import_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -392,7 +403,7 @@ impl StatementRules for PythonCoreParser {
         Ok(Box::new(SyntaxNode::ImportNameStmtNode(pos, self.lexer.position, symbol, right)))
     }
 
-    fn parse_import_from_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_import_from_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone(); /* 'from' */
         self.lexer.advance();
@@ -410,56 +421,42 @@ impl StatementRules for PythonCoreParser {
             }
         }
 
-        dots.reverse();
-
         let left = match (&*self.lexer.symbol, dots.len()) {
             ( Token::ImportToken( _ , _ , _ ), 0 ) => {
-                return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME literal or dot(s) before 'import' in import statement!"))))
+                return Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME literal or dot(s) before 'import' in import statement!", FOLLOW_FROM))
             },
             ( Token::ImportToken( _ , _ , _ ), _  ) => None,
             ( _ , _ ) => Some(self.parse_dotted_name_stmt()?)
         };
 
+        let symbol2 = self.expect_token(Token::ImportToken, "Expecting 'import' in from import statement!");
+
         match &*self.lexer.symbol {
-            Token::ImportToken( _ , _ , _ ) => {
-                let symbol2 = self.lexer.symbol.clone();
+            Token::MultiplyToken( _ , _ , _ ) => { /* '*' */
+                let symbol3 = self.lexer.symbol.clone();
                 self.lexer.advance();
 
-                match &*self.lexer.symbol {
-                    Token::MultiplyToken( _ , _ , _ ) => { /* '*' */
-                        let symbol3 = self.lexer.symbol.clone();
-                        self.lexer.advance();
-
-                        Ok(Box::new(SyntaxNode::ImportFromStmtNode(pos, self.lexer.position, symbol, dots, left, symbol2, Some(symbol3), None, None)))
-                    },
-                    Token::LeftParenToken( _ , _ , _ ) => {
-                        let symbol3 = self.lexer.symbol.clone();
-                        self.lexer.advance();
+                Ok(Box::new(SyntaxNode::ImportFromStmtNode(pos, self.lexer.position, symbol, dots, left, symbol2, Some(symbol3), None, None)))
+            },
+            Token::LeftParenToken( _ , _ , _ ) => {
+                let symbol3 = self.lexer.symbol.clone();
+                self.lexer.advance();
 
-                        let right = self.parse_import_as_names_stmt()?;
+                let right = self.parse_import_as_names_stmt()?;
 
-                        match &*self.lexer.symbol {
-                            Token::RightParenToken( _ , _ , _ ) => {
-                                let symbol4 = self.lexer.symbol.clone();
-                                self.lexer.advance();
+                let symbol4 = self.expect_token(Token::RightParenToken, "Expecting ')' in from import statement!");
 
-                                Ok(Box::new(SyntaxNode::ImportFromStmtNode(pos, self.lexer.position, symbol, dots, left, symbol2, Some(symbol3), Some(right), Some(symbol4))))
-                            },
-                            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ')' in from import statement!"))))
-                        }
-                    },
-                    _ => {
-                        let right = self.parse_import_as_names_stmt()?;
-
-                        Ok(Box::new(SyntaxNode::ImportFromStmtNode(pos, self.lexer.position, symbol, dots, left, symbol2, None, Some(right), None)))
-                    }
-                }
+                Ok(Box::new(SyntaxNode::ImportFromStmtNode(pos, self.lexer.position, symbol, dots, left, symbol2, Some(symbol3), Some(right), Some(symbol4))))
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'import' in from import statement!"))))
+            _ => {
+                let right = self.parse_import_as_names_stmt()?;
+
+                Ok(Box::new(SyntaxNode::ImportFromStmtNode(pos, self.lexer.position, symbol, dots, left, symbol2, None, Some(right), None)))
+            }
         }
     }
 
-    fn parse_import_as_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_import_as_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
 
         match &*self.lexer.symbol {
@@ -479,17 +476,17 @@ impl StatementRules for PythonCoreParser {
 
                                 Ok(Box::new(SyntaxNode::ImportAsNameStmtNode(pos, self.lexer.position, symbol1, Some(symbol2), Some(symbol3))))
                             },
-                            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME literal after 'as' in import statement!"))))
+                            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME literal after 'as' in import statement!", &["NAME"]))
                         }
                     },
                     _ => Ok(Box::new(SyntaxNode::ImportAsNameStmtNode(pos, self.lexer.position, symbol1, None, None)))
                 }
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME literal in import statement!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME literal in import statement!", &["NAME"]))
         }
     }
 
-    fn parse_dotted_as_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_dotted_as_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let left = self.parse_dotted_name_stmt()?;
 
@@ -504,14 +501,14 @@ impl StatementRules for PythonCoreParser {
 
                         Ok(Box::new(SyntaxNode::DottedAsNameStmtNode(pos, self.lexer.position, left, symbol, right)))
                     },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME literal after 'as' in import statement!"))))
+                    _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME literal after 'as' in import statement!", &["NAME"]))
                 }
             },
             _ => Ok(left)
         }
     }
 
-    fn parse_import_as_names_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_import_as_names_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -536,7 +533,7 @@ impl StatementRules for PythonCoreParser {
         })
     }
 
-    fn parse_dotted_as_names_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_dotted_as_names_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -561,7 +558,7 @@ impl StatementRules for PythonCoreParser {
         })
     }
 
-    fn parse_dotted_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_dotted_name_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
        let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut sepators = Vec::<Box<Token>>::new();
@@ -580,23 +577,20 @@ impl StatementRules for PythonCoreParser {
                                 Token::NameToken( _ , _ , _ , _ ) => {
                                     nodes.push(self.parse_atom_expr()?);
                                 },
-                                _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME literal in import statement after '.'!"))))
+                                _ => return Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME literal in import statement after '.'!", &["NAME"]))
                             }
                         },
                         _ => break
                     }
                 }
 
-                nodes.reverse();
-                sepators.reverse();
-
                 Ok(Box::new(SyntaxNode::DottedNameStmtNode(pos, self.lexer.position, nodes, sepators)))
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME literal in import statement!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME literal in import statement!", &["NAME"]))
         }
     }
 
-    fn parse_global_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_global_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -618,23 +612,20 @@ impl StatementRules for PythonCoreParser {
                                 Token::NameToken( _ , _ , _ , _ ) => {
                                     nodes.push(self.parse_atom_expr()?);
                                 },
-                                _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME literal in 'global' statement!"))))
+                                _ => return Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME literal in 'global' statement!", &["NAME"]))
                             }
                         },
                         _ => break
                     }
                 }
 
-                nodes.reverse();
-                separators.reverse();
-
                 Ok(Box::new(SyntaxNode::GlobalStmtNode(pos, self.lexer.position, symbol, nodes, separators)))
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting at least one NAME literal in 'global' statement!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting at least one NAME literal in 'global' statement!", &["NAME"]))
         }
     }
 
-    fn parse_nonlocal_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_nonlocal_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
@@ -656,23 +647,20 @@ impl StatementRules for PythonCoreParser {
                                 Token::NameToken( _ , _ , _ , _ ) => {
                                     nodes.push(self.parse_atom_expr()?);
                                 },
-                                _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME literal in 'nonlocal' statement!"))))
+                                _ => return Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME literal in 'nonlocal' statement!", &["NAME"]))
                             }
                         },
                         _ => break
                     }
                 }
 
-                nodes.reverse();
-                separators.reverse();
-
                 Ok(Box::new(SyntaxNode::NonlocalStmtNode(pos, self.lexer.position, symbol, nodes, separators)))
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting at least one NAME literal in 'nonlocal' statement!"))))
+            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting at least one NAME literal in 'nonlocal' statement!", &["NAME"]))
         }
     }
 
-    fn parse_assert_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_assert_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -692,7 +680,46 @@ impl StatementRules for PythonCoreParser {
         }
     }
 
-    fn parse_compound_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn is_type_alias_stmt(&mut self) -> bool {
+        match &*self.lexer.symbol {
+            /* 'type' is a soft keyword, only a NAME after it makes it a statement */
+            Token::NameToken( _ , _ , text , _ ) if text.as_str() == "type" => matches!(self.lexer.peek(1), Token::NameToken( _ , _ , _ , _ )),
+            _ => false
+        }
+    }
+
+    fn parse_type_alias_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
+        let pos = self.lexer.position;
+
+        let symbol1 = match &*self.lexer.symbol {
+            Token::NameToken( s , e , _ , t ) => Box::new(Token::TypeToken(*s, *e, t.clone())),
+            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting 'type' keyword in 'type' statement!", &["type"]))
+        };
+        self.lexer.require_feature(Feature::TypeParameters, pos);
+        self.lexer.advance();
+
+        let symbol2 = match &*self.lexer.symbol {
+            Token::NameToken( _ , _ , _ , _ ) => {
+                let symbol = self.lexer.symbol.clone();
+                self.lexer.advance();
+                symbol
+            },
+            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME in 'type' statement!", &["NAME"]))
+        };
+
+        let left = match &*self.lexer.symbol {
+            Token::LeftSquareBracketToken( _ , _ , _ ) => Some(self.parse_type_params()?),
+            _ => None
+        };
+
+        let symbol3 = self.expect_token(Token::AssignToken, "Expecting '=' in 'type' statement!");
+
+        let right = self.parse_test_expr()?;
+
+        Ok(Box::new(SyntaxNode::TypeAliasStmtNode(pos, self.lexer.position, symbol1, symbol2, left, symbol3, right)))
+    }
+
+    fn parse_compound_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         match &*self.lexer.symbol {
             Token::IfToken( _ , _ , _ ) => self.parse_if_stmt(),
             Token::ForToken( _ , _ , _ ) => self.parse_for_stmt(),
@@ -707,263 +734,205 @@ impl StatementRules for PythonCoreParser {
         }
     }
 
-    fn parse_async_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_async_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
 
         let right = match &*self.lexer.symbol {
             Token::DefToken( _ , _ , _ ) => self.parse_func_def_stmt()?,
-            Token::ClassToken( _ , _ , _ ) => self.parse_class_stmt()?,
             Token::WithToken( _ , _ , _ ) => self.parse_with_stmt()?,
-            _ => return Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'def', 'class' or 'with' after 'async' statement!"))))
+            Token::ForToken( _ , _ , _ ) => self.parse_for_stmt()?,
+            _ => return Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting 'def', 'with' or 'for' after 'async' statement!", FOLLOW_ASYNC))
         };
 
         Ok(Box::new(SyntaxNode::AsyncStmtNode(pos, self.lexer.position, symbol, right)))
     }
 
-    fn parse_if_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_if_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
 
         let left = self.parse_named_expr()?;
 
-        match &*self.lexer.symbol {
-            Token::ColonToken( _ , _ , _ ) => {
-                let symbol2 = self.lexer.symbol.clone();
-                self.lexer.advance();
-
-                let right = self.parse_suite_stmt()?;
+        let symbol2 = self.expect_token(Token::ColonToken, "Expecting ':' in 'if' statement!");
 
-                let mut nodes = Vec::<Box<SyntaxNode>>::new();
+        let right = self.parse_suite_stmt()?;
 
-                loop {
-                    match &*self.lexer.symbol {
-                        Token::ElseToken( _ , _ , _ ) => {
-                            nodes.push(self.parse_elif_stmt()?);
-                        },
-                        _ => break
-                    }
-                }
-
-                let else_part = match &*self.lexer.symbol {
-                    Token::ElseToken( _, _ , _ ) => {
-                        Some(self.parse_else_stmt()?)
-                    },
-                    _ => None
-                };
+        let mut nodes = Vec::<Box<SyntaxNode>>::new();
 
-                nodes.reverse();
+        loop {
+            match &*self.lexer.symbol {
+                Token::ElifToken( _ , _ , _ ) => {
+                    nodes.push(self.parse_elif_stmt()?);
+                },
+                _ => break
+            }
+        }
 
-                Ok(Box::new(SyntaxNode::IfStmtNode(pos, self.lexer.position, symbol, left, symbol2, right, nodes, else_part)))
+        let else_part = match &*self.lexer.symbol {
+            Token::ElseToken( _, _ , _ ) => {
+                Some(self.parse_else_stmt()?)
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'if' statement!"))))
-        }
+            _ => None
+        };
+
+        Ok(Box::new(SyntaxNode::IfStmtNode(pos, self.lexer.position, symbol, left, symbol2, right, nodes, else_part)))
     }
 
-    fn parse_elif_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_elif_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
 
         let left = self.parse_named_expr()?;
 
-        match &*self.lexer.symbol {
-            Token::ColonToken( _ , _ , _ ) => {
-                let symbol2 = self.lexer.symbol.clone();
-                self.lexer.advance();
+        let symbol2 = self.expect_token(Token::ColonToken, "Expecting ':' in 'elif' statement!");
 
-                let right = self.parse_suite_stmt()?;
+        let right = self.parse_suite_stmt()?;
 
-                Ok(Box::new(SyntaxNode::ElifStmtNode(pos, self.lexer.position, symbol, left, symbol2, right)))
-            },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'elif' statement!"))))
-        }
+        Ok(Box::new(SyntaxNode::ElifStmtNode(pos, self.lexer.position, symbol, left, symbol2, right)))
     }
 
-    fn parse_else_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_else_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
 
-        match &*self.lexer.symbol {
-            Token::ColonToken( _ , _ , _ ) => {
-                let symbol2 = self.lexer.symbol.clone();
-                self.lexer.advance();
+        let symbol2 = self.expect_token(Token::ColonToken, "Expecting ':' in 'else' statement!");
 
-                let right = self.parse_suite_stmt()?;
+        let right = self.parse_suite_stmt()?;
 
-                Ok(Box::new(SyntaxNode::ElseStmtNode(pos, self.lexer.position, symbol, symbol2, right)))
-            },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'else' statement!"))))
-        }
+        Ok(Box::new(SyntaxNode::ElseStmtNode(pos, self.lexer.position, symbol, symbol2, right)))
     }
 
-    fn parse_while_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_while_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
 
         let left = self.parse_named_expr()?;
 
-        match &*self.lexer.symbol {
-            Token::ColonToken( _ , _ , _ ) => {
-                let symbol2 = self.lexer.symbol.clone();
-                self.lexer.advance();
+        let symbol2 = self.expect_token(Token::ColonToken, "Expecting ':' in 'while' statement!");
 
-                let right = self.parse_suite_stmt()?;
+        let right = self.parse_suite_stmt()?;
 
-                let else_part = match &*self.lexer.symbol {
-                    Token::ElseToken( _, _ , _ ) => Some(self.parse_else_stmt()?),
-                    _ => None
-                };
+        let else_part = match &*self.lexer.symbol {
+            Token::ElseToken( _, _ , _ ) => Some(self.parse_else_stmt()?),
+            _ => None
+        };
 
-                Ok(Box::new(SyntaxNode::WhileStmtNode(pos, self.lexer.position, symbol, left, symbol2, right, else_part)))
-            },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'while' statement!"))))
-        }
+        Ok(Box::new(SyntaxNode::WhileStmtNode(pos, self.lexer.position, symbol, left, symbol2, right, else_part)))
     }
 
-    fn parse_for_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_for_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
 
         let left = self.parse_expr_list_expr()?;
 
-        match &*self.lexer.symbol {
-            Token::InToken( _ , _ , _ ) => {
-                let symbol2 = self.lexer.symbol.clone();
-                self.lexer.advance();
+        let symbol2 = self.expect_token(Token::InToken, "Expecting 'in' in 'for' statement!");
 
-                let right = self.parse_test_list_expr()?;
+        let right = self.parse_test_list_expr()?;
 
-                match &*self.lexer.symbol {
-                    Token::ColonToken( _ , _ , _ ) => {
-                        let symbol3 = self.lexer.symbol.clone();
-                        self.lexer.advance();
+        let symbol3 = self.expect_token(Token::ColonToken, "Expecting ':' in 'for' statement!");
 
-                        let tc = match &*self.lexer.symbol {
-                            Token::TypeCommentToken( _ , _ , _ , _ ) => {
-                                let symbol5 = self.lexer.symbol.clone();
-                                self.lexer.advance();
-                                Some(symbol5)
-                            },
-                            _ => None
-                        };
+        let tc = match &*self.lexer.symbol {
+            Token::TypeCommentToken( _ , _ , _ , _ ) => {
+                let symbol5 = self.lexer.symbol.clone();
+                self.lexer.advance();
+                Some(symbol5)
+            },
+            _ => None
+        };
 
-                        let next = self.parse_suite_stmt()?;
+        let next = self.parse_suite_stmt()?;
 
-                        let else_part = match &*self.lexer.symbol {
-                            Token::ElseToken( _, _ , _ ) => Some(self.parse_else_stmt()?),
-                            _ => None
-                        };
+        let else_part = match &*self.lexer.symbol {
+            Token::ElseToken( _, _ , _ ) => Some(self.parse_else_stmt()?),
+            _ => None
+        };
 
-                        Ok(Box::new(SyntaxNode::ForStmtNode(pos, self.lexer.position, symbol, left, symbol2, right, symbol3, tc, next, else_part)))
-                    },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'for' statement!"))))
-                }
-            },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'in' in 'for' statement!"))))
-        }
+        Ok(Box::new(SyntaxNode::ForStmtNode(pos, self.lexer.position, symbol, left, symbol2, right, symbol3, tc, next, else_part)))
     }
 
-    fn parse_try_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_try_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
 
+        let symbol2 = self.expect_token(Token::ColonToken, "Expecting ':' in 'try' statement!");
+
+        let left = self.parse_suite_stmt()?;
+
         match &*self.lexer.symbol {
-            Token::ColonToken( _ , _ , _ ) => {
-                let symbol2 = self.lexer.symbol.clone();
+            Token::FinallyToken( _ , _ , _ ) => {
+                let symbol3 = self.lexer.symbol.clone();
                 self.lexer.advance();
 
-                let left = self.parse_suite_stmt()?;
+                let symbol4 = self.expect_token(Token::ColonToken, "Expecting ':' in 'finally' statement!");
+                let next = self.parse_suite_stmt()?;
+
+                Ok(Box::new(SyntaxNode::TryStmtNode(pos, self.lexer.position, symbol, symbol2, left, Vec::new(), None, Some(symbol3), Some(symbol4), Some(next))))
+            },
+            _ => {
+                let mut nodes = Vec::<Box<SyntaxNode>>::new();
 
                 match &*self.lexer.symbol {
-                    Token::FinallyToken( _ , _ , _ ) => {
-                        let symbol3 = self.lexer.symbol.clone();
-                        self.lexer.advance();
+                    Token::ExceptToken( _ , _ , _ ) => {
+                        let is_star = matches!(self.lexer.peek(1), Token::MultiplyToken( _ , _ , _ ));
+                        nodes.push(self.parse_except_stmt()?);
+
+                        loop {
+                            match &*self.lexer.symbol {
+                                Token::ExceptToken( _ , _ , _ ) => {
+                                    if matches!(self.lexer.peek(1), Token::MultiplyToken( _ , _ , _ )) != is_star {
+                                        return Err(self.error(DiagnosticCode::InvalidExceptClause, "cannot have both 'except' and 'except*' on the same 'try'"))
+                                    }
+                                    nodes.push(self.parse_except_stmt()?)
+                                },
+                                _ => break
+                            }
+                        }
+
+                        let else_part = match &*self.lexer.symbol {
+                            Token::ElseToken( _ , _ , _ ) => Some(self.parse_else_stmt()?),
+                            _ => None
+                        };
 
                         match &*self.lexer.symbol {
-                            Token::ColonToken( _ , _ , _ ) => {
-                                let symbol4 = self.lexer.symbol.clone();
+                            Token::FinallyToken( _ , _ , _ ) => {
+                                let symbol3 = self.lexer.symbol.clone();
                                 self.lexer.advance();
+
+                                let symbol4 = self.expect_token(Token::ColonToken, "Expecting ':' in 'finally' statement!");
                                 let next = self.parse_suite_stmt()?;
 
-                                Ok(Box::new(SyntaxNode::TryStmtNode(pos, self.lexer.position, symbol, symbol2, left, Vec::new(), None, Some(symbol3), Some(symbol4), Some(next))))
+                                Ok(Box::new(SyntaxNode::TryStmtNode(pos, self.lexer.position, symbol, symbol2, left, nodes, else_part, Some(symbol3), Some(symbol4), Some(next))))
                             },
-                            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'finally' statement!"))))
+                            _ => Ok(Box::new(SyntaxNode::TryStmtNode(pos, self.lexer.position, symbol, symbol2, left, nodes, else_part, None, None, None)))
                         }
                     },
-                    _ => {
-                        let mut nodes = Vec::<Box<SyntaxNode>>::new();
-
-                        match &*self.lexer.symbol {
-                            Token::ExceptToken( _ , _ , _ ) => {
-                                nodes.push(self.parse_except_stmt()?);
-
-                                loop {
-                                    match &*self.lexer.symbol {
-                                        Token::ExceptToken( _ , _ , _ ) => nodes.push(self.parse_except_stmt()?),
-                                        _ => break
-                                    }
-                                }
-
-                                nodes.reverse();
-
-                                let else_part = match &*self.lexer.symbol {
-                                    Token::ElseToken( _ , _ , _ ) => Some(self.parse_else_stmt()?),
-                                    _ => None
-                                };
-
-                                match &*self.lexer.symbol {
-                                    Token::FinallyToken( _ , _ , _ ) => {
-                                        let symbol3 = self.lexer.symbol.clone();
-                                        self.lexer.advance();
-
-                                        match &*self.lexer.symbol {
-                                            Token::ColonToken( _ , _ , _ ) => {
-                                                let symbol4 = self.lexer.symbol.clone();
-                                                self.lexer.advance();
-                                                let next = self.parse_suite_stmt()?;
-
-                                                Ok(Box::new(SyntaxNode::TryStmtNode(pos, self.lexer.position, symbol, symbol2, left, Vec::new(), else_part, Some(symbol3), Some(symbol4), Some(next))))
-                                            },
-                                            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'finally' statement!"))))
-                                        }
-                                    },
-                                    _ => Ok(Box::new(SyntaxNode::TryStmtNode(pos, self.lexer.position, symbol, symbol2, left, nodes, else_part, None, None, None)))
-                                }
-                            },
-                            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'except' in 'try' statement!"))))
-                        }
-                    }
+                    _ => Err(self.error_expecting(DiagnosticCode::ExpectedToken, "Expecting 'except' in 'try' statement!", FOLLOW_TRY_BODY))
                 }
-            },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'try' statement!"))))
+            }
         }
     }
 
-    fn parse_except_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_except_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let left = self.parse_except_clause_stmt()?;
 
-        match &*self.lexer.symbol {
-            Token::ColonToken( _ , _ , _ ) => {
-                let symbol = self.lexer.symbol.clone();
-                self.lexer.advance();
+        let symbol = self.expect_token(Token::ColonToken, "Expecting ':' in 'except' statement!");
 
-                let right = self.parse_suite_stmt()?;
+        let right = self.parse_suite_stmt()?;
 
-                Ok(Box::new(SyntaxNode::ExceptStmtNode(pos, self.lexer.position, left, symbol, right)))
-            },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'with' statement!"))))
-        }
+        Ok(Box::new(SyntaxNode::ExceptStmtNode(pos, self.lexer.position, left, symbol, right)))
     }
 
-    fn parse_with_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_with_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
@@ -971,6 +940,15 @@ impl StatementRules for PythonCoreParser {
         let mut nodes = Vec::<Box<SyntaxNode>>::new();
         let mut separators = Vec::<Box<Token>>::new();
 
+        let left_paren = match self.is_parenthesized_with_items() {
+            true => {
+                let symbol1 = self.lexer.symbol.clone();
+                self.lexer.advance();
+                Some(symbol1)
+            },
+            _ => None
+        };
+
         nodes.push(self.parse_with_item_stmt()?);
 
         loop {
@@ -979,39 +957,84 @@ impl StatementRules for PythonCoreParser {
                     separators.push(self.lexer.symbol.clone());
                     self.lexer.advance();
 
-                    nodes.push(self.parse_with_item_stmt()?)
+                    match &*self.lexer.symbol {
+                        Token::RightParenToken( _ , _ , _ ) if left_paren.is_some() => break,
+                        _ => nodes.push(self.parse_with_item_stmt()?)
+                    }
                 },
                 _ => break
             }
         }
 
-        nodes.reverse();
-        separators.reverse();
+        let right_paren = match left_paren {
+            Some( ref symbol1 ) => {
+                /* Before 3.10 the parentheses only parse as an expression, which cannot hold an 'as' target */
+                if nodes.iter().any(|node| matches!(&**node, SyntaxNode::WithItemStmtNode( _ , _ , _ , Some( _ ) , _ ))) {
+                    self.lexer.require_feature(Feature::ParenthesizedWithItems, symbol1.span().0)
+                }
 
+                Some(self.expect_token(Token::RightParenToken, "Expecting ')' in 'with' statement!"))
+            },
+            None => None
+        };
+
+        let symbol2 = self.expect_token(Token::ColonToken, "Expecting ':' in 'with' statement!");
+
+        let tc = match &*self.lexer.symbol {
+            Token::TypeCommentToken( _ , _ , _ , _ ) => {
+                let symbol3 = self.lexer.symbol.clone();
+                self.lexer.advance();
+
+                Some(symbol3)
+            },
+            _ => None
+        };
+
+        let next = self.parse_suite_stmt()?;
+
+        Ok(Box::new(SyntaxNode::WithStmtNode(pos, self.lexer.position, symbol, left_paren, nodes, separators, right_paren, symbol2, tc, next)))
+    }
+
+    /// Whether the 'with' items are in parentheses, as in 'with (open(a) as f, open(b) as g):', and not a
+    /// parenthesized expression as in 'with (a or b) as f:'. Only items, an optional trailing ',' and ')' followed
+    /// by ':' make it the parenthesized form.
+    fn is_parenthesized_with_items(&mut self) -> bool {
         match &*self.lexer.symbol {
-            Token::ColonToken( _ , _ , _ ) => {
-                let symbol2 = self.lexer.symbol.clone();
+            Token::LeftParenToken( _ , _ , _ ) => {
+                let saved = self.lexer.clone();
                 self.lexer.advance();
 
-                let tc = match &*self.lexer.symbol {
-                    Token::TypeCommentToken( _ , _ , _ , _ ) => {
-                        let symbol3 = self.lexer.symbol.clone();
-                        self.lexer.advance();
+                let mut is_items = self.parse_with_item_stmt().is_ok();
+
+                while is_items {
+                    match &*self.lexer.symbol {
+                        Token::CommaToken( _ , _ , _ ) => {
+                            self.lexer.advance();
+
+                            if !matches!(&*self.lexer.symbol, Token::RightParenToken( _ , _ , _ )) {
+                                is_items = self.parse_with_item_stmt().is_ok()
+                            }
+                        },
+                        _ => break
+                    }
+                }
 
-                        Some(symbol3)
+                is_items = is_items && match &*self.lexer.symbol {
+                    Token::RightParenToken( _ , _ , _ ) => {
+                        self.lexer.advance();
+                        matches!(&*self.lexer.symbol, Token::ColonToken( _ , _ , _ ))
                     },
-                    _ => None
+                    _ => false
                 };
 
-                let next = self.parse_suite_stmt()?;
-
-                Ok(Box::new(SyntaxNode::WithStmtNode(pos, self.lexer.position, symbol, nodes, separators, symbol2, tc, next)))
+                self.lexer = saved;
+                is_items
             },
-            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting ':' in 'with' statement!"))))
+            _ => false
         }
     }
 
-    fn parse_with_item_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_with_item_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let left = self.parse_test_expr()?;
 
@@ -1028,15 +1051,37 @@ impl StatementRules for PythonCoreParser {
         }
     }
 
-    fn parse_except_clause_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    /// 'except' clause, or 'except*' handling an exception group, which needs at least one exception type.
+    /// Several types without parentheses, as in 'except A, B:', become a test list.
+    fn parse_except_clause_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         let symbol = self.lexer.symbol.clone();
         self.lexer.advance();
 
+        let star = match &*self.lexer.symbol {
+            Token::MultiplyToken( _ , _ , _ ) => {
+                self.lexer.require_feature(Feature::ExceptionGroups, pos);
+
+                let symbol1 = self.lexer.symbol.clone();
+                self.lexer.advance();
+                Some(symbol1)
+            },
+            _ => None
+        };
+
         match &*self.lexer.symbol {
-            Token::ColonToken( _ , _ , _ ) => Ok(Box::new(SyntaxNode::ExceptClauseStmtNode(pos, self.lexer.position, symbol, None, None, None))),
+            Token::ColonToken( _ , _ , _ ) if star.is_some() => Err(self.error_expecting(DiagnosticCode::InvalidExceptClause, "expected one or more exception types", FIRST_EXPRESSION)),
+            Token::ColonToken( _ , _ , _ ) => Ok(Box::new(SyntaxNode::ExceptClauseStmtNode(pos, self.lexer.position, symbol, None, None, None, None))),
             _ => {
-                let left = self.parse_test_expr()?;
+                let left = self.parse_test_list_expr()?;
+
+                /* 'except A, B:' is a single clause for both types since 3.14, 'except (A, B) as e:' needs the parentheses */
+                if let SyntaxNode::TestListExprNode(start, end, _ , _ ) = &*left {
+                    match &*self.lexer.symbol {
+                        Token::AsToken( _ , _ , _ ) => return Err(Box::new(Diagnostic::error(DiagnosticCode::InvalidExceptClause, *start, *end, String::from("multiple exception types must be parenthesized when using 'as'")))),
+                        _ => self.lexer.require_feature(Feature::UnparenthesizedExceptTypes, *start)
+                    }
+                }
 
                 match &*self.lexer.symbol {
                     Token::AsToken( _ , _ , _ ) => {
@@ -1045,20 +1090,20 @@ impl StatementRules for PythonCoreParser {
 
                         match &*self.lexer.symbol {
                             Token::NameToken( _ , _ , _ , _ ) => {
-                                let right = self.parse_suite_stmt()?;
+                                let right = self.parse_atom_expr()?;
 
-                                Ok(Box::new(SyntaxNode::ExceptClauseStmtNode(pos, self.lexer.position, symbol, Some(left), Some(symbol2), Some(right))))
+                                Ok(Box::new(SyntaxNode::ExceptClauseStmtNode(pos, self.lexer.position, symbol, star, Some(left), Some(symbol2), Some(right))))
                             },
-                            _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting NAME literal in except clause in 'try' statement!"))))
+                            _ => Err(self.error_expecting(DiagnosticCode::ExpectedName, "Expecting NAME literal in except clause in 'try' statement!", &["NAME"]))
                         }
                     },
-                    _ => Ok(Box::new(SyntaxNode::ExceptClauseStmtNode(pos, self.lexer.position, symbol, Some(left), None, None)))
+                    _ => Ok(Box::new(SyntaxNode::ExceptClauseStmtNode(pos, self.lexer.position, symbol, star, Some(left), None, None)))
                 }
             }
         }
     }
 
-    fn parse_suite_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<SyntaxError>> {
+    fn parse_suite_stmt(&mut self) -> Result<Box<SyntaxNode>, Box<Diagnostic>> {
         let pos = self.lexer.position;
         match &*self.lexer.symbol {
             Token::NewlineToken( _ , _ , _ , _ , _ ) => {
@@ -1072,23 +1117,19 @@ impl StatementRules for PythonCoreParser {
 
                         let mut nodes = Vec::<Box<SyntaxNode>>::new();
 
-                        nodes.push(self.parse_stmt()?);
-
                         loop {
                             match &*self.lexer.symbol {
                                 Token::DedentToken( _ , _ , _ ) => break,
-                                _ => nodes.push(self.parse_stmt()?)
+                                _ => nodes.push(self.parse_stmt_or_recover())
                             }
                         }
 
-                        nodes.reverse();
-
                         let symbol3 = self.lexer.symbol.clone();
                         self.lexer.advance();
 
                         Ok(Box::new(SyntaxNode::SuiteStmtNode(pos, self.lexer.position, symbol1, symbol2, nodes, symbol3)))
                     },
-                    _ => Err(Box::new(SyntaxError::new(self.lexer.position, String::from("Expecting 'indent' statement!"))))
+                    _ => Err(self.error_expecting(DiagnosticCode::ExpectedIndent, "Expecting 'indent' statement!", &["INDENT"]))
                 }
             },
             _ => self.parse_simple_stmt()
@@ -1099,8 +1140,151 @@ impl StatementRules for PythonCoreParser {
 // Unittests for statement grammar rules ///////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use crate::parser::diagnostic::DiagnosticCode;
+    use crate::parser::parser_options::ParserOptions;
+    use crate::parser::python_core_parser::PythonCoreParser;
+    use crate::parser::python_core_statement_parser::StatementRules;
+    use crate::parser::python_version::PythonVersion;
+    use crate::parser::syntax_nodes::SyntaxNode;
+    use crate::parser::token_nodes::Token;
+
     #[test]
     fn dummy_test() {
         assert_eq!(1 == 1, true);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn parse_type_alias_stmt() {
+        let mut parser = PythonCoreParser::new("type Alias[T] = list[T]\n");
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::SimpleStmtNode( _ , _ , nodes , _ , _ ) => {
+                assert!(matches!(&*nodes[0], SyntaxNode::TypeAliasStmtNode(0, 23, symbol , name , Some(params) , _ , _ )
+                    if matches!(&**symbol, Token::TypeToken(0, 4, _ )) && name.text() == "Alias" && matches!(&**params, SyntaxNode::TypeParamsNode( _ , _ , _ , list , _ , _ ) if list.len() == 1)))
+            },
+            _ => panic!("Expecting simple statement!")
+        }
+        assert!(parser.lexer.errors.is_empty());
+    }
+
+    #[test]
+    fn parse_type_as_name() {
+        for source in ["type(x)\n", "type = 1\n", "type.x = 1\n", "print(type)\n"] {
+            let mut parser = PythonCoreParser::new(source);
+            let node = parser.parse_stmt().unwrap();
+            assert!(matches!(&*node, SyntaxNode::SimpleStmtNode( _ , _ , nodes , _ , _ ) if !matches!(&*nodes[0], SyntaxNode::TypeAliasStmtNode( .. ))), "{}", source)
+        }
+    }
+
+    #[test]
+    fn parse_type_alias_stmt_before_python_312() {
+        let mut parser = PythonCoreParser::with_options("type X = int\n", &ParserOptions { target: PythonVersion::Python311, ..ParserOptions::default() });
+        assert!(parser.parse_stmt().is_ok());
+        assert_eq!(parser.lexer.errors[0].message, "type parameters and type aliases are only supported in Python 3.12 and greater, target is Python 3.11");
+    }
+
+    #[test]
+    fn parse_try_stmt_with_except_star() {
+        let mut parser = PythonCoreParser::new("try:\n    pass\nexcept* ValueError as eg:\n    pass\nexcept* (TypeError, KeyError):\n    pass\nfinally:\n    pass\n");
+        let node = parser.parse_stmt().unwrap();
+
+        match &*node {
+            SyntaxNode::TryStmtNode( _ , _ , _ , _ , _ , nodes , None , Some( _ ) , Some( _ ) , Some( _ ) ) => {
+                assert_eq!(nodes.len(), 2);
+                assert!(matches!(&*nodes[0], SyntaxNode::ExceptStmtNode( _ , _ , clause , _ , _ )
+                    if matches!(&**clause, SyntaxNode::ExceptClauseStmtNode(14, 38, _ , Some(star) , Some( _ ) , Some( _ ) , Some( _ )) if matches!(&**star, Token::MultiplyToken(20, 21, _ )))));
+                assert!(matches!(&*nodes[1], SyntaxNode::ExceptStmtNode( _ , _ , clause , _ , _ )
+                    if matches!(&**clause, SyntaxNode::ExceptClauseStmtNode( _ , _ , _ , Some( _ ) , Some( _ ) , None , None ))))
+            },
+            _ => panic!("Expecting try statement!")
+        }
+        assert!(parser.lexer.errors.is_empty());
+    }
+
+    #[test]
+    fn parse_try_stmt_mixing_except_and_except_star() {
+        let mut parser = PythonCoreParser::new("try:\n    pass\nexcept ValueError:\n    pass\nexcept* TypeError:\n    pass\n");
+        assert!(matches!(parser.parse_stmt(), Err(e) if matches!(e.code, DiagnosticCode::InvalidExceptClause)
+            && e.message == "cannot have both 'except' and 'except*' on the same 'try'" && (e.start, e.end) == (42, 48)));
+    }
+
+    #[test]
+    fn parse_bare_except_star() {
+        let mut parser = PythonCoreParser::new("try:\n    pass\nexcept*:\n    pass\n");
+        assert!(matches!(parser.parse_stmt(), Err(e) if e.message == "expected one or more exception types" && e.expected.contains(&String::from("NAME")) && e.expected.contains(&String::from("not"))));
+    }
+
+    #[test]
+    fn parse_except_star_before_python_311() {
+        let mut parser = PythonCoreParser::with_options("try:\n    pass\nexcept* ValueError:\n    pass\n", &ParserOptions { target: PythonVersion::Python310, ..ParserOptions::default() });
+        assert!(parser.parse_stmt().is_ok());
+        assert_eq!(parser.lexer.errors[0].message, "exception groups are only supported in Python 3.11 and greater, target is Python 3.10");
+    }
+
+    #[test]
+    fn parse_with_stmt_parenthesized_items() {
+        let mut parser = PythonCoreParser::new("with (\n    open(a) as f,\n    open(b) as g,\n):\n    pass\n");
+        let node = parser.parse_stmt().unwrap();
+
+        assert!(matches!(&*node, SyntaxNode::WithStmtNode( _ , _ , _ , Some(left) , nodes , separators , Some(right) , _ , None , _ )
+            if matches!(&**left, Token::LeftParenToken(5, 6, _ )) && nodes.len() == 2 && separators.len() == 2 && matches!(&**right, Token::RightParenToken(43, 44, _ ))
+                && nodes.iter().all(|node| matches!(&**node, SyntaxNode::WithItemStmtNode( _ , _ , _ , Some( _ ) , Some( _ ) )))));
+        assert!(parser.lexer.errors.is_empty());
+    }
+
+    #[test]
+    fn parse_with_stmt_parenthesized_expression() {
+        for source in ["with (a or b) as f:\n    pass\n", "with (a, b)[0] as f:\n    pass\n", "with (yield x):\n    pass\n"] {
+            let mut parser = PythonCoreParser::new(source);
+            let node = parser.parse_stmt().unwrap();
+            assert!(matches!(&*node, SyntaxNode::WithStmtNode( _ , _ , _ , None , nodes , _ , None , _ , _ , _ ) if nodes.len() == 1), "{}", source)
+        }
+    }
+
+    #[test]
+    fn parse_with_stmt_parenthesized_items_before_python_310() {
+        let mut parser = PythonCoreParser::with_options("with (open(a) as f, open(b) as g):\n    pass\n", &ParserOptions { target: PythonVersion::Python39, ..ParserOptions::default() });
+        assert!(parser.parse_stmt().is_ok());
+        assert_eq!(parser.lexer.errors[0].message, "parenthesized context managers are only supported in Python 3.10 and greater, target is Python 3.9");
+
+        let mut parser = PythonCoreParser::with_options("with (a, b):\n    pass\n", &ParserOptions { target: PythonVersion::Python39, ..ParserOptions::default() });
+        assert!(parser.parse_stmt().is_ok());
+        assert!(parser.lexer.errors.is_empty());
+    }
+
+    #[test]
+    fn parse_except_clause_with_unparenthesized_types() {
+        let mut parser = PythonCoreParser::new("try:\n    pass\nexcept TimeoutError, ConnectionError:\n    pass\n");
+        let node = parser.parse_stmt().unwrap();
+
+        assert!(matches!(&*node, SyntaxNode::TryStmtNode( _ , _ , _ , _ , _ , nodes , _ , _ , _ , _ )
+            if matches!(&*nodes[0], SyntaxNode::ExceptStmtNode( _ , _ , clause , _ , _ )
+                if matches!(&**clause, SyntaxNode::ExceptClauseStmtNode( _ , _ , _ , None , Some(left) , None , None ) if matches!(&**left, SyntaxNode::TestListExprNode( _ , _ , types , _ ) if types.len() == 2)))));
+        assert!(parser.lexer.errors.is_empty());
+    }
+
+    #[test]
+    fn parse_except_clause_with_unparenthesized_types_and_as() {
+        let mut parser = PythonCoreParser::new("try:\n    pass\nexcept A, B as e:\n    pass\n");
+        assert!(matches!(parser.parse_stmt(), Err(e) if matches!(e.code, DiagnosticCode::InvalidExceptClause)
+            && e.message == "multiple exception types must be parenthesized when using 'as'" && (e.start, e.end) == (21, 26)));
+    }
+
+    #[test]
+    fn parse_except_clause_with_unparenthesized_types_before_python_314() {
+        let mut parser = PythonCoreParser::with_options("try:\n    pass\nexcept* A, B:\n    pass\n", &ParserOptions { target: PythonVersion::Python313, ..ParserOptions::default() });
+        assert!(parser.parse_stmt().is_ok());
+        assert_eq!(parser.lexer.errors[0].message, "multiple exception types without parentheses are only supported in Python 3.14 and greater, target is Python 3.13");
+    }
+
+    #[test]
+    fn parse_async_stmt() {
+        let mut parser = PythonCoreParser::new("async for x in y:\n    pass\n");
+        let node = parser.parse_stmt().unwrap();
+        assert!(matches!(&*node, SyntaxNode::AsyncStmtNode(0, _ , _ , right ) if matches!(&**right, SyntaxNode::ForStmtNode(6, .. ))));
+
+        let mut parser = PythonCoreParser::new("async class A:\n    pass\n");
+        assert!(matches!(parser.parse_stmt(), Err(e) if e.message == "Expecting 'def', 'with' or 'for' after 'async' statement!" && e.expected == vec!["def", "with", "for"]));
+    }
+}
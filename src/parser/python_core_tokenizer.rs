@@ -1,26 +1,1754 @@
-use crate::parser::token_nodes::Token::Invalid;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use super::parser_options::ParserOptions;
+use super::python_version::{Feature, PythonVersion};
+use super::source_decoder::{coding_declaration, decode_source};
+use super::diagnostic::{Diagnostic, DiagnosticCode};
 use super::token_nodes::Token;
+use super::trivia_nodes::Trivia;
+
+/// Maximum nesting of indented blocks, same limit as CPython.
+const MAX_INDENT_LEVEL: usize = 100;
+
+/// Keywords allowed directly after a number with only a warning, as in '1if x else y'.
+const KEYWORDS_AFTER_NUMBER: [&str; 8] = ["and", "else", "for", "if", "in", "is", "not", "or"];
 
 pub trait LexerMethods {
-    fn new() -> PythonCoreTokenizer;
-    fn advance(&mut self) -> ();
+    fn new(source: &str) -> PythonCoreTokenizer;
+    fn with_target(source: &str, target: PythonVersion) -> PythonCoreTokenizer;
+    fn with_options(source: &str, options: &ParserOptions) -> PythonCoreTokenizer;
+    fn from_bytes(source: &[u8], options: &ParserOptions) -> PythonCoreTokenizer;
+    fn advance(&mut self);
+    fn peek(&mut self, n: usize) -> &Token;
+}
+
+/// Lexer state for an f-string or t-string being tokenized, stacked for strings nested in
+/// replacement fields.
+#[derive(Clone)]
+struct FStringMode {
+    is_template: bool,
+    quote: char,
+    is_triple: bool,
+    is_raw: bool,
+    /* Offset of the opening quote and bracket depth outside the f-string */
+    start: usize,
+    brackets: usize,
+    /* Open replacement fields as bracket depth after their '{' and whether in the format spec */
+    fields: Vec<(usize, bool)>,
 }
 
+/// Cloning is cheap as the source text is shared, the parser clones the lexer to look ahead.
+#[derive(Clone)]
 pub struct PythonCoreTokenizer
 {
     pub symbol: Box<Token>,
     pub position: u32,
+    pub errors: Vec<Box<Diagnostic>>,
+    pub warnings: Vec<Box<Diagnostic>>,
+    /* Target version, feature toggles and whether comments like '# type: int' become type comment tokens */
+    pub options: ParserOptions,
+    /* Offset and tag, as in '[attr]', of each '# type: ignore' comment seen with type comments on */
+    pub type_ignores: Vec<(u32, String)>,
+    /* Encoding the source was decoded from, "utf-8" unless given as bytes with a coding declaration */
+    pub encoding: &'static str,
+    source: Rc<[char]>,
+    index: usize,
+    /* End of the text to tokenize, before the end of the source for a type comment */
+    end: usize,
+    at_beginning_of_line: bool,
+    /* Indentation columns with tabs to multiple of 8 and tabs as a single column */
+    indent_stack: Vec<(u32, u32)>,
+    pending_dedents: u32,
+    /* Open brackets with their offset, NEWLINE and indentation are ignored while not empty */
+    brackets: Vec<(char, u32)>,
+    trivia: Vec<Box<Trivia>>,
+    fstrings: Vec<FStringMode>,
+    /* Start of the comment holding the PEP 263 coding declaration */
+    coding_declaration: Option<usize>,
+    /* Tokens scanned ahead of 'symbol' by peek, consumed by advance before scanning more */
+    lookahead: VecDeque<Box<Token>>,
 }
 
 impl LexerMethods for PythonCoreTokenizer {
-    fn new() -> PythonCoreTokenizer {
+    fn new(source: &str) -> PythonCoreTokenizer {
+        PythonCoreTokenizer::with_target(source, PythonVersion::LATEST)
+    }
+
+    fn with_target(source: &str, target: PythonVersion) -> PythonCoreTokenizer {
+        PythonCoreTokenizer::with_options(source, &ParserOptions { target, ..ParserOptions::default() })
+    }
+
+    fn with_options(source: &str, options: &ParserOptions) -> PythonCoreTokenizer {
+        let source: Vec<char> = source.chars().collect();
+        let mut trivia = Vec::<Box<Trivia>>::new();
+        let mut index = 0;
+
+        if source.first() == Some(&'\u{feff}') {
+            trivia.push(Box::new(Trivia::ByteOrderMark(0, 1)));
+            index = 1
+        }
+
+        let head: String = source[index..].iter().take(1024).collect();
+        let coding_declaration = coding_declaration(&head).map(|(start, _ , _ )| start + index);
+
         PythonCoreTokenizer {
-            symbol: Box::new(Invalid),
+            symbol: Box::new(Token::BadToken(0, 0, Box::default(), Vec::new())), /* Until the first advance */
             position: 0,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            options: options.clone(),
+            type_ignores: Vec::new(),
+            encoding: "utf-8",
+            end: source.len(),
+            source: source.into(),
+            index,
+            at_beginning_of_line: true,
+            indent_stack: vec![(0, 0)],
+            pending_dedents: 0,
+            brackets: Vec::new(),
+            trivia,
+            fstrings: Vec::new(),
+            coding_declaration,
+            lookahead: VecDeque::new(),
         }
     }
 
+    /// Tokenizer for the raw bytes of a file, decoded as given by its BOM or coding declaration.
+    /// Decoding errors come first in 'errors' and are positioned at byte offsets.
+    fn from_bytes(source: &[u8], options: &ParserOptions) -> PythonCoreTokenizer {
+        let decoded = decode_source(source);
+        let mut lexer = PythonCoreTokenizer::with_options(&decoded.text, options);
+        lexer.errors = decoded.errors;
+        lexer.encoding = decoded.encoding;
+        lexer
+    }
+
     fn advance(&mut self) {
+        let symbol = match self.lookahead.pop_front() {
+            Some(symbol) => symbol,
+            None => self.scan_token()
+        };
+
+        self.position = symbol.span().0;
+        self.symbol = symbol
+    }
+
+    /// Token 'n' positions after the current symbol without consuming it, 'peek(0)' is the
+    /// current symbol. Peeking past the end of the source gives the EOF token.
+    fn peek(&mut self, n: usize) -> &Token {
+        while self.lookahead.len() < n {
+            let is_eof = match self.lookahead.back() {
+                Some(symbol) => matches!(&**symbol, Token::EofToken( _ , _ , _ )),
+                None => matches!(&*self.symbol, Token::EofToken( _ , _ , _ ))
+            };
+            if is_eof {
+                break
+            }
+
+            let symbol = self.scan_token();
+            self.lookahead.push_back(symbol)
+        }
+
+        match n {
+            0 => &self.symbol,
+            _ => match self.lookahead.get(n - 1) {
+                Some(symbol) => symbol,
+                None => self.lookahead.back().map_or(&*self.symbol, |symbol| &**symbol)
+            }
+        }
+    }
+}
+
+/// Tokens following the current symbol up to and including EOF, for tools that only need the
+/// token stream. A new tokenizer starts with the first token of the source.
+impl Iterator for PythonCoreTokenizer {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        match &*self.symbol {
+            Token::EofToken( _ , _ , _ ) => None,
+            _ => {
+                self.advance();
+                Some((*self.symbol).clone())
+            }
+        }
+    }
+}
+
+impl PythonCoreTokenizer {
+    fn peek_char(&self, offset: usize) -> char {
+        match self.index + offset < self.end {
+            true => self.source[self.index + offset],
+            _ => '\0'
+        }
+    }
+
+    fn is_eof(&self) -> bool {
+        self.index >= self.end
+    }
+
+    /// Reports a feature used at 'position' that is turned off or newer than the target version.
+    pub fn require_feature(&mut self, feature: Feature, position: u32) {
+        if !self.options.is_enabled(feature) {
+            self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::UnsupportedFeature, position, position, format!("{} {} only supported in Python {} and greater, target is Python {}",
+                feature.text(), feature.verb(), feature.version().text(), self.options.target.text()))))
+        }
+    }
+
+    /// Tokenizer for the type in a type comment token, as '(int) -> str' in '# type: (int) -> str',
+    /// with offsets into the same source.
+    pub fn type_comment_lexer(&self, symbol: &Token) -> PythonCoreTokenizer {
+        let (start, end) = symbol.span();
+        let mut lexer = self.clone();
+        lexer.errors = Vec::new();
+        lexer.warnings = Vec::new();
+        lexer.options.type_comments = false;
+        lexer.type_ignores = Vec::new();
+        lexer.index = start as usize;
+        lexer.end = end as usize;
+        lexer.at_beginning_of_line = false;
+        lexer.indent_stack = vec![(0, 0)];
+        lexer.pending_dedents = 0;
+        lexer.brackets = Vec::new();
+        lexer.trivia = Vec::new();
+        lexer.fstrings = Vec::new();
+        lexer.lookahead = VecDeque::new();
+
+        lexer.index += 1;
+        lexer.skip_spaces();
+        lexer.index += 5; /* 'type:' */
+        lexer.skip_spaces();
+        lexer
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek_char(0) == ' ' || self.peek_char(0) == '\t' {
+            self.index += 1
+        }
+    }
+
+    /// True at a '#' starting a type comment that is not a type ignore comment, when type
+    /// comments are enabled.
+    fn is_type_comment(&self) -> bool {
+        self.options.type_comments && self.type_comment_rest().is_some_and(|rest| type_ignore_tag(&rest).is_none())
+    }
+
+    /// Text after 'type:' and any spaces when at a '#' starting a type comment.
+    fn type_comment_rest(&self) -> Option<String> {
+        let mut offset = 1;
+        while self.peek_char(offset) == ' ' || self.peek_char(offset) == '\t' {
+            offset += 1
+        }
 
+        if !"type:".chars().enumerate().all(|(index, ch)| self.peek_char(offset + index) == ch) {
+            return None
+        }
+        offset += 5;
+        while self.peek_char(offset) == ' ' || self.peek_char(offset) == '\t' {
+            offset += 1
+        }
+
+        let mut rest = String::new();
+        while !matches!(self.peek_char(offset), '\r' | '\n' | '\0') {
+            rest.push(self.peek_char(offset));
+            offset += 1
+        }
+        Some(rest)
+    }
+
+    fn text(&self, start: usize, end: usize) -> String {
+        self.source[start..end].iter().collect()
+    }
+
+    /// Next token from the source with its trailing trivia.
+    fn scan_token(&mut self) -> Box<Token> {
+        let mut symbol = self.next_token();
+
+        match &*symbol {
+            Token::NewlineToken( _ , _ , _ , _ , _ ) |
+            Token::IndentToken( _ , _ , _ ) |
+            Token::DedentToken( _ , _ , _ ) |
+            Token::EofToken( _ , _ , _ ) => (),
+            _ if self.is_in_fstring_text() => (), /* Whitespace is part of the string */
+            _ => {
+                let trailing = self.scan_trivia(true);
+                symbol.trivia_mut().extend(trailing)
+            }
+        }
+
+        symbol
+    }
+
+    fn next_token(&mut self) -> Box<Token> {
+        if self.pending_dedents > 0 {
+            self.pending_dedents -= 1;
+            return Box::new(Token::DedentToken(self.index as u32, self.index as u32, Vec::new()))
+        }
+
+        if self.is_in_fstring_text() {
+            return self.handle_fstring_text()
+        }
+
+        if self.at_beginning_of_line {
+            if let Some(symbol) = self.handle_indentation() {
+                return symbol
+            }
+        }
+
+        loop {
+            let leading = self.scan_trivia(false);
+            self.trivia.extend(leading);
+
+            if self.is_eof() {
+                return self.handle_end_of_file()
+            }
+
+            let start = self.index;
+            match self.peek_char(0) {
+                '\r' | '\n' => {
+                    let (ch1, ch2) = self.skip_newline();
+                    let (s, e) = (start as u32, self.index as u32);
+
+                    if self.brackets.is_empty() {
+                        self.at_beginning_of_line = true;
+                        return Box::new(Token::NewlineToken(s, e, ch1, ch2, std::mem::take(&mut self.trivia)))
+                    }
+
+                    /* Implicit line joining, blank line inside brackets */
+                    self.trivia.push(Box::new(Trivia::Newline(s, e, ch1, ch2)))
+                },
+                ch if is_identifier_start(ch) => return self.handle_name(),
+                '0'..='9' => return self.handle_number(),
+                '.' if self.peek_char(1).is_ascii_digit() => return self.handle_number(),
+                '\'' | '"' => return self.handle_string(start),
+                ':' | '}' if self.is_at_fstring_field() => return self.handle_fstring_field_delimiter(),
+                '!' if self.peek_char(1) != '=' && self.is_at_fstring_field() => return self.handle_fstring_field_delimiter(),
+                '#' => { /* Left by scan_trivia as it is a type comment */
+                    while !self.is_eof() && self.peek_char(0) != '\r' && self.peek_char(0) != '\n' {
+                        self.index += 1
+                    }
+                    let text = self.text(start, self.index).into_boxed_str();
+                    return Box::new(Token::TypeCommentToken(start as u32, self.index as u32, text, std::mem::take(&mut self.trivia)))
+                },
+                '\\' => {
+                    self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::InvalidCharacter, start as u32, start as u32 + 1, String::from("unexpected character after line continuation character"))));
+                    return self.bad_token(start, start + 1)
+                },
+                ch => {
+                    let mut trivia = std::mem::take(&mut self.trivia);
+
+                    for length in [3, 2, 1] {
+                        if start + length > self.end {
+                            continue
+                        }
+                        let text = self.text(start, start + length);
+                        match operator_or_delimiter(&text, start as u32, (start + length) as u32, trivia) {
+                            Ok(symbol) => {
+                                self.index += length;
+                                self.track_brackets(&symbol);
+                                return Box::new(symbol)
+                            },
+                            Err(rest) => trivia = rest
+                        }
+                    }
+
+                    self.trivia = trivia;
+                    self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::InvalidCharacter, start as u32, start as u32 + 1, invalid_character_message(ch))));
+                    return self.bad_token(start, start + 1)
+                }
+            }
+        }
     }
-}
\ No newline at end of file
+
+    /// Measures the indentation of a new logical line, skipping blank and comment only lines.
+    /// Returns an INDENT or DEDENT token when the indentation level changes. Like CPython the
+    /// column is measured twice, with tab stops of 8 and 1, and any disagreement between the
+    /// two means tabs and spaces are mixed in a way that depends on the tab size.
+    fn handle_indentation(&mut self) -> Option<Box<Token>> {
+        loop {
+            let mut column: u32 = 0;
+            let mut alt_column: u32 = 0;
+            let mut start = self.index;
+
+            loop {
+                match self.peek_char(0) {
+                    ' ' => {
+                        column += 1;
+                        alt_column += 1
+                    },
+                    '\t' => {
+                        column = (column / 8 + 1) * 8;
+                        alt_column += 1
+                    },
+                    '\x0c' => {
+                        self.push_whitespace(start);
+                        self.trivia.push(Box::new(Trivia::FormFeed(self.index as u32, self.index as u32 + 1)));
+                        start = self.index + 1;
+                        column = 0;
+                        alt_column = 0
+                    },
+                    _ => break
+                }
+                self.index += 1
+            }
+
+            self.push_whitespace(start);
+
+            if self.is_eof() {
+                return None
+            }
+
+            match self.peek_char(0) {
+                '#' if self.is_type_comment() => { /* Followed by NEWLINE, indentation is measured on the next line */
+                    self.at_beginning_of_line = false;
+                    return None
+                },
+                '#' | '\r' | '\n' => { /* Blank line, never part of indentation */
+                    let comment = self.scan_trivia(false);
+                    self.trivia.extend(comment);
+                    let start = self.index as u32;
+                    let (ch1, ch2) = self.skip_newline();
+                    if ch1 != '\0' {
+                        self.trivia.push(Box::new(Trivia::Newline(start, self.index as u32, ch1, ch2)))
+                    }
+                    continue
+                },
+                _ => ()
+            }
+
+            self.at_beginning_of_line = false;
+            let pos = self.index as u32;
+            let (current, alt_current) = *self.indent_stack.last().unwrap();
+
+            if column == current {
+                if alt_column != alt_current {
+                    self.inconsistent_tabs_error(pos)
+                }
+                return None
+            }
+
+            if column > current {
+                if alt_column <= alt_current {
+                    self.inconsistent_tabs_error(pos)
+                }
+                if self.indent_stack.len() >= MAX_INDENT_LEVEL {
+                    self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::Indentation, pos, pos, String::from("too many levels of indentation"))));
+                    return None
+                }
+                self.indent_stack.push((column, alt_column));
+                return Some(Box::new(Token::IndentToken(pos, pos, Vec::new())))
+            }
+
+            while column < self.indent_stack.last().unwrap().0 {
+                self.indent_stack.pop();
+                self.pending_dedents += 1
+            }
+
+            let (current, alt_current) = *self.indent_stack.last().unwrap();
+            if column != current {
+                self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::Indentation, pos, pos, String::from("unindent does not match any outer indentation level"))))
+            }
+            else if alt_column != alt_current {
+                self.inconsistent_tabs_error(pos)
+            }
+
+            self.pending_dedents -= 1;
+            return Some(Box::new(Token::DedentToken(pos, pos, Vec::new())))
+        }
+    }
+
+    fn inconsistent_tabs_error(&mut self, pos: u32) {
+        self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::Indentation, pos, pos, String::from("inconsistent use of tabs and spaces in indentation"))))
+    }
+
+    /// Keeps the stack of open brackets, reporting closing brackets without a matching opener.
+    fn track_brackets(&mut self, symbol: &Token) {
+        let (ch, pos) = match symbol {
+            Token::LeftParenToken(s, _ , _ ) => ('(', *s),
+            Token::LeftSquareBracketToken(s, _ , _ ) => ('[', *s),
+            Token::LeftCurlyBracketToken(s, _ , _ ) => ('{', *s),
+            Token::RightParenToken(s, _ , _ ) => (')', *s),
+            Token::RightSquareBracketToken(s, _ , _ ) => (']', *s),
+            Token::RightCurlyBracketToken(s, _ , _ ) => ('}', *s),
+            _ => return
+        };
+
+        match ch {
+            '(' | '[' | '{' => self.brackets.push((ch, pos)),
+            _ => match self.brackets.pop() {
+                Some(('(', _ )) if ch == ')' => (),
+                Some(('[', _ )) if ch == ']' => (),
+                Some(('{', _ )) if ch == '}' => (),
+                Some((open, open_pos)) => {
+                    self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::UnmatchedBracket, pos, pos + 1, format!("closing parenthesis '{}' does not match opening parenthesis '{}'", ch, open))
+                        .with_label(open_pos, open_pos + 1, "opening parenthesis here")))
+                },
+                None => {
+                    self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::UnmatchedBracket, pos, pos + 1, format!("unmatched '{}'", ch))))
+                }
+            }
+        }
+    }
+
+    fn handle_end_of_file(&mut self) -> Box<Token> {
+        let pos = self.index as u32;
+
+        if let Some(mode) = self.fstrings.first() {
+            self.brackets.truncate(mode.brackets)
+        }
+        for mode in std::mem::take(&mut self.fstrings) {
+            self.report_unterminated_fstring(&mode)
+        }
+
+        for (open, start) in std::mem::take(&mut self.brackets) {
+            self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::UnmatchedBracket, start, start + 1, format!("'{}' was never closed", open))))
+        }
+
+        if !self.at_beginning_of_line {
+            self.at_beginning_of_line = true;
+            return Box::new(Token::NewlineToken(pos, pos, '\0', '\0', std::mem::take(&mut self.trivia)))
+        }
+
+        if self.indent_stack.len() > 1 {
+            self.indent_stack.pop();
+            return Box::new(Token::DedentToken(pos, pos, Vec::new()))
+        }
+
+        Box::new(Token::EofToken(pos, pos, std::mem::take(&mut self.trivia)))
+    }
+
+    fn push_whitespace(&mut self, start: usize) {
+        if self.index > start {
+            let text = self.text(start, self.index);
+            self.trivia.push(Box::new(Trivia::Whitespace(start as u32, self.index as u32, Box::new(text))))
+        }
+    }
+
+    /// Collects whitespace, comments and line continuations. Leading trivia stops in front of
+    /// anything else, trailing trivia also stops after the line break that ends the line of
+    /// its token, being either a line continuation or a newline inside brackets.
+    fn scan_trivia(&mut self, is_trailing: bool) -> Vec<Box<Trivia>> {
+        let mut trivia = Vec::<Box<Trivia>>::new();
+
+        loop {
+            let start = self.index;
+            match self.peek_char(0) {
+                ' ' | '\t' => {
+                    while self.peek_char(0) == ' ' || self.peek_char(0) == '\t' {
+                        self.index += 1
+                    }
+                    trivia.push(Box::new(Trivia::Whitespace(start as u32, self.index as u32, Box::new(self.text(start, self.index)))))
+                },
+                '\x0c' => {
+                    self.index += 1;
+                    trivia.push(Box::new(Trivia::FormFeed(start as u32, self.index as u32)))
+                },
+                '#' if self.is_type_comment() => break,
+                '#' => {
+                    if self.options.type_comments {
+                        if let Some(tag) = self.type_comment_rest().and_then(|rest| type_ignore_tag(&rest)) {
+                            self.type_ignores.push((start as u32, tag))
+                        }
+                    }
+                    while !self.is_eof() && self.peek_char(0) != '\r' && self.peek_char(0) != '\n' {
+                        self.index += 1
+                    }
+                    let text = Box::new(self.text(start, self.index));
+                    trivia.push(Box::new(match self.coding_declaration == Some(start) {
+                        true => Trivia::EncodingDeclaration(start as u32, self.index as u32, text),
+                        _ => Trivia::Comment(start as u32, self.index as u32, text)
+                    }))
+                },
+                '\\' => { /* Explicit line joining */
+                    match self.peek_char(1) {
+                        '\r' | '\n' => {
+                            self.index += 1;
+                            let (ch1, ch2) = self.skip_newline();
+                            trivia.push(Box::new(Trivia::LineContinuation(start as u32, self.index as u32, ch1, ch2)));
+                            if is_trailing {
+                                break
+                            }
+                        },
+                        _ if self.index + 1 >= self.end => {
+                            self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::UnexpectedEof, start as u32, start as u32 + 1, String::from("unexpected EOF while parsing"))));
+                            self.index += 1;
+                            trivia.push(Box::new(Trivia::LineContinuation(start as u32, self.index as u32, '\0', '\0')))
+                        },
+                        _ => break
+                    }
+                },
+                '\r' | '\n' if is_trailing && !self.brackets.is_empty() => { /* Implicit line joining */
+                    let (ch1, ch2) = self.skip_newline();
+                    trivia.push(Box::new(Trivia::Newline(start as u32, self.index as u32, ch1, ch2)));
+                    break
+                },
+                _ => break
+            }
+        }
+
+        trivia
+    }
+
+    /// Consumes '\n', '\r' or '\r\n' and returns the two characters for the NEWLINE token.
+    fn skip_newline(&mut self) -> (char, char) {
+        match (self.peek_char(0), self.peek_char(1)) {
+            ('\r', '\n') => {
+                self.index += 2;
+                ('\r', '\n')
+            },
+            ('\r', _ ) => {
+                self.index += 1;
+                ('\r', '\0')
+            },
+            ('\n', _ ) => {
+                self.index += 1;
+                ('\n', '\0')
+            },
+            _ => ('\0', '\0')
+        }
+    }
+
+    fn handle_name(&mut self) -> Box<Token> {
+        let start = self.index;
+
+        while is_identifier_continue(self.peek_char(0)) {
+            self.index += 1
+        }
+
+        let text = self.text(start, self.index);
+        if is_string_prefix(&text) && (self.peek_char(0) == '\'' || self.peek_char(0) == '"') {
+            return self.handle_string(start)
+        }
+        if (is_fstring_prefix(&text) || is_tstring_prefix(&text)) && (self.peek_char(0) == '\'' || self.peek_char(0) == '"') {
+            return self.handle_fstring_start(start)
+        }
+
+        let (s, e) = (start as u32, self.index as u32);
+        let trivia = std::mem::take(&mut self.trivia);
+
+        Box::new(match keyword(&text, s, e, trivia) {
+            Ok(symbol) => symbol,
+            Err(trivia) => Token::NameToken(s, e, Box::new(text), trivia)
+        })
+    }
+
+    fn handle_number(&mut self) -> Box<Token> {
+        let start = self.index;
+        let errors = self.errors.len();
+
+        match (self.peek_char(0), self.peek_char(1)) {
+            ('0', 'x' | 'X') => self.handle_radix_number(start, "hexadecimal", |ch| ch.is_ascii_hexdigit()),
+            ('0', 'o' | 'O') => self.handle_radix_number(start, "octal", |ch| ('0'..='7').contains(&ch)),
+            ('0', 'b' | 'B') => self.handle_radix_number(start, "binary", |ch| ch == '0' || ch == '1'),
+            _ => self.handle_decimal_number(start)
+        }
+
+        if self.errors.len() > errors {
+            return self.bad_token(start, self.index)
+        }
+
+        let text = self.text(start, self.index);
+        Box::new(Token::NumberToken(start as u32, self.index as u32, Box::new(text), std::mem::take(&mut self.trivia)))
+    }
+
+    /// Hexadecimal, octal or binary integer, an underscore is allowed directly after the prefix.
+    fn handle_radix_number(&mut self, start: usize, kind: &str, is_digit: fn(char) -> bool) {
+        self.index += 2;
+        if self.peek_char(0) == '_' {
+            self.index += 1
+        }
+
+        if !is_digit(self.peek_char(0)) {
+            match self.peek_char(0) {
+                ch if ch.is_ascii_digit() => self.invalid_number(self.index, format!("invalid digit '{}' in {} literal", ch, kind)),
+                _ => self.invalid_number(start, format!("invalid {} literal", kind))
+            }
+            return
+        }
+
+        if !self.scan_digits(kind, is_digit) {
+            return
+        }
+
+        match self.peek_char(0) {
+            ch if ch.is_ascii_digit() => self.invalid_number(self.index, format!("invalid digit '{}' in {} literal", ch, kind)),
+            _ => self.verify_end_of_number(kind)
+        }
+    }
+
+    fn handle_decimal_number(&mut self, start: usize) {
+        let mut is_integer = true;
+
+        if self.peek_char(0) != '.' && !self.scan_digits("decimal", |ch| ch.is_ascii_digit()) {
+            return
+        }
+
+        if self.peek_char(0) == '.' {
+            is_integer = false;
+            self.index += 1;
+            if self.peek_char(0).is_ascii_digit() && !self.scan_digits("decimal", |ch| ch.is_ascii_digit()) {
+                return
+            }
+        }
+
+        if self.peek_char(0) == 'e' || self.peek_char(0) == 'E' {
+            let exponent = self.index;
+            self.index += 1;
+            if self.peek_char(0) == '+' || self.peek_char(0) == '-' {
+                self.index += 1
+            }
+
+            match self.peek_char(0) {
+                '0'..='9' => {
+                    is_integer = false;
+                    if !self.scan_digits("decimal", |ch| ch.is_ascii_digit()) {
+                        return
+                    }
+                },
+                _ => self.index = exponent /* Not an exponent, as in '1else' */
+            }
+        }
+
+        if self.peek_char(0) == 'j' || self.peek_char(0) == 'J' {
+            self.index += 1;
+            self.verify_end_of_number("imaginary");
+            return
+        }
+
+        let text = self.text(start, self.index);
+        if is_integer && text.starts_with('0') && text.chars().any(|ch| ch != '0' && ch != '_') {
+            self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::InvalidNumber, start as u32, self.index as u32, String::from("leading zeros in decimal integer literals are not permitted; use an 0o prefix for octal integers"))));
+            return
+        }
+
+        self.verify_end_of_number("decimal")
+    }
+
+    /// Consumes digits separated by single underscores. A doubled or trailing underscore makes
+    /// the literal invalid, which is reported and false returned.
+    fn scan_digits(&mut self, kind: &str, is_digit: fn(char) -> bool) -> bool {
+        loop {
+            while is_digit(self.peek_char(0)) {
+                self.index += 1
+            }
+
+            if self.peek_char(0) != '_' {
+                return true
+            }
+
+            self.index += 1;
+            if !is_digit(self.peek_char(0)) {
+                self.invalid_number(self.index - 1, format!("invalid {} literal", kind));
+                return false
+            }
+        }
+    }
+
+    /// A number directly followed by a name is an error, except for the keywords that older
+    /// code relies on, as in '1if x else y', which only gives a warning.
+    fn verify_end_of_number(&mut self, kind: &str) {
+        let ch = self.peek_char(0);
+        if !is_identifier_continue(ch) {
+            return
+        }
+
+        let is_keyword = KEYWORDS_AFTER_NUMBER.iter().any(|keyword| {
+            keyword.chars().enumerate().all(|(offset, ch)| self.peek_char(offset) == ch)
+        });
+
+        match is_keyword {
+            true => self.warnings.push(Box::new(Diagnostic::warning(DiagnosticCode::InvalidNumber, self.index as u32, self.index as u32, format!("invalid {} literal", kind)))),
+            false => self.invalid_number(self.index, format!("invalid {} literal", kind))
+        }
+    }
+
+    /// Reports an invalid literal and consumes the rest of it, so it stays a single token.
+    fn invalid_number(&mut self, position: usize, message: String) {
+        self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::InvalidNumber, position as u32, position as u32 + 1, message)));
+        while is_identifier_continue(self.peek_char(0)) || self.peek_char(0) == '.' {
+            self.index += 1
+        }
+    }
+
+    /// String literal starting at 'start' with any prefix already consumed. An unterminated
+    /// string is reported at its opening quote and becomes a bad token running to the end of
+    /// the line, or the end of the file when triple-quoted.
+    fn handle_string(&mut self, start: usize) -> Box<Token> {
+        let is_bytes = self.text(start, self.index).to_lowercase().contains('b');
+        let quote_start = self.index;
+        let quote = self.peek_char(0);
+        let is_triple = self.peek_char(1) == quote && self.peek_char(2) == quote;
+        self.index += if is_triple { 3 } else { 1 };
+
+        loop {
+            match self.peek_char(0) {
+                _ if self.is_eof() => {
+                    let message = match is_triple {
+                        true => "unterminated triple-quoted string literal",
+                        _ => "unterminated string literal"
+                    };
+                    self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::UnterminatedString, quote_start as u32, self.index as u32, format!("{} (detected at line {})", message, self.line_number(self.index)))));
+                    return self.bad_token(start, self.index)
+                },
+                '\r' | '\n' if !is_triple => {
+                    self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::UnterminatedString, quote_start as u32, self.index as u32, format!("unterminated string literal (detected at line {})", self.line_number(self.index)))));
+                    return self.bad_token(start, self.index)
+                },
+                '\\' => { /* Escaped character, also keeps a quote inside raw strings */
+                    self.index += 1;
+                    match self.peek_char(0) {
+                        '\r' | '\n' => { self.skip_newline(); },
+                        _ if self.is_eof() => (),
+                        _ => self.index += 1
+                    }
+                },
+                ch if ch == quote && (!is_triple || (self.peek_char(1) == quote && self.peek_char(2) == quote)) => {
+                    self.index += if is_triple { 3 } else { 1 };
+                    break
+                },
+                ch => {
+                    if is_bytes && !ch.is_ascii() {
+                        self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::InvalidString, self.index as u32, self.index as u32 + 1, String::from("bytes can only contain ASCII literal characters"))))
+                    }
+                    self.index += 1
+                }
+            }
+        }
+
+        let text = self.text(start, self.index);
+        Box::new(Token::StringToken(start as u32, self.index as u32, vec![Box::new(text)], std::mem::take(&mut self.trivia)))
+    }
+
+    /// Token for source text that was reported as an error, lexing continues after it.
+    fn bad_token(&mut self, start: usize, end: usize) -> Box<Token> {
+        self.index = end;
+        let text = self.text(start, end);
+        Box::new(Token::BadToken(start as u32, end as u32, Box::new(text), std::mem::take(&mut self.trivia)))
+    }
+
+    /// FSTRING_START or TSTRING_START with prefix and opening quote, switching the lexer into
+    /// f-string mode. Template strings are reported when the target is older than Python 3.14.
+    fn handle_fstring_start(&mut self, start: usize) -> Box<Token> {
+        let is_template = is_tstring_prefix(&self.text(start, self.index));
+        if is_template {
+            self.require_feature(Feature::TemplateStrings, start as u32)
+        }
+
+        let quote_start = self.index;
+        let quote = self.peek_char(0);
+        let is_triple = self.peek_char(1) == quote && self.peek_char(2) == quote;
+        self.index += if is_triple { 3 } else { 1 };
+
+        let text = self.text(start, self.index);
+        self.fstrings.push(FStringMode {
+            is_template,
+            quote,
+            is_triple,
+            is_raw: text.to_lowercase().contains('r'),
+            start: quote_start,
+            brackets: self.brackets.len(),
+            fields: Vec::new()
+        });
+
+        let (s, e, trivia) = (start as u32, self.index as u32, std::mem::take(&mut self.trivia));
+        Box::new(match is_template {
+            true => Token::TStringStartToken(s, e, Box::new(text), trivia),
+            _ => Token::FStringStartToken(s, e, Box::new(text), trivia)
+        })
+    }
+
+    /// Literal part or format spec of an f-string. Returns FSTRING_MIDDLE for any text, otherwise
+    /// the '{' or '}' around a replacement field or FSTRING_END at the closing quote.
+    fn handle_fstring_text(&mut self) -> Box<Token> {
+        let mode = self.fstrings.last().unwrap();
+        let (is_template, quote, is_triple, is_raw) = (mode.is_template, mode.quote, mode.is_triple, mode.is_raw);
+        let in_format_spec = !mode.fields.is_empty();
+        let kind = if is_template { "t-string" } else { "f-string" };
+        let start = self.index;
+
+        loop {
+            match self.peek_char(0) {
+                _ if self.is_eof() => break,
+                '\r' | '\n' if !is_triple => break,
+                ch if ch == quote && (!is_triple || (self.peek_char(1) == quote && self.peek_char(2) == quote)) => break,
+                '{' if !in_format_spec && self.peek_char(1) == '{' => self.index += 2,
+                '}' if !in_format_spec && self.peek_char(1) == '}' => self.index += 2,
+                '{' => break,
+                '}' if in_format_spec => break,
+                '}' => {
+                    self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::InvalidFString, self.index as u32, self.index as u32 + 1, format!("{}: single '}}' is not allowed", kind))));
+                    self.index += 1
+                },
+                '\\' if !is_raw && self.peek_char(1) == 'N' && self.peek_char(2) == '{' => { /* Named unicode escape */
+                    while !self.is_eof() && self.peek_char(0) != '}' && self.peek_char(0) != quote {
+                        self.index += 1
+                    }
+                    if self.peek_char(0) == '}' {
+                        self.index += 1
+                    }
+                },
+                '\\' => { /* A brace after it still starts or ends a replacement field */
+                    self.index += 1;
+                    match self.peek_char(0) {
+                        '\r' | '\n' => { self.skip_newline(); },
+                        '{' | '}' => (),
+                        _ if self.is_eof() => (),
+                        _ => self.index += 1
+                    }
+                },
+                _ => self.index += 1
+            }
+        }
+
+        let trivia = std::mem::take(&mut self.trivia);
+        if self.index > start {
+            let text = self.text(start, self.index);
+            return Box::new(match is_template {
+                true => Token::TStringMiddleToken(start as u32, self.index as u32, Box::new(text), trivia),
+                _ => Token::FStringMiddleToken(start as u32, self.index as u32, Box::new(text), trivia)
+            })
+        }
+
+        let (s, e) = (start as u32, start as u32 + 1);
+        match self.peek_char(0) {
+            '{' if !self.is_eof() => {
+                self.index += 1;
+                self.brackets.push(('{', s));
+                let depth = self.brackets.len();
+                self.fstrings.last_mut().unwrap().fields.push((depth, false));
+                Box::new(Token::LeftCurlyBracketToken(s, e, trivia))
+            },
+            '}' if !self.is_eof() => {
+                self.index += 1;
+                self.brackets.pop();
+                self.fstrings.last_mut().unwrap().fields.pop();
+                Box::new(Token::RightCurlyBracketToken(s, e, trivia))
+            },
+            ch if ch == quote && !self.is_eof() => {
+                if in_format_spec {
+                    self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::InvalidFString, s, e, format!("{}: expecting '}}'", kind))))
+                }
+                self.index += if is_triple { 3 } else { 1 };
+                let mode = self.fstrings.pop().unwrap();
+                self.brackets.truncate(mode.brackets);
+                let text = Box::new(self.text(start, self.index));
+                Box::new(match is_template {
+                    true => Token::TStringEndToken(s, self.index as u32, text, trivia),
+                    _ => Token::FStringEndToken(s, self.index as u32, text, trivia)
+                })
+            },
+            _ => {
+                let mode = self.fstrings.pop().unwrap();
+                self.brackets.truncate(mode.brackets);
+                self.report_unterminated_fstring(&mode);
+                Box::new(match is_template {
+                    true => Token::TStringEndToken(s, s, Box::default(), trivia),
+                    _ => Token::FStringEndToken(s, s, Box::default(), trivia)
+                })
+            }
+        }
+    }
+
+    /// Top level ':', '!' or '}' of a replacement field, starting the format spec, the conversion
+    /// or ending the field.
+    fn handle_fstring_field_delimiter(&mut self) -> Box<Token> {
+        let (s, e) = (self.index as u32, self.index as u32 + 1);
+        let trivia = std::mem::take(&mut self.trivia);
+        let ch = self.peek_char(0);
+        self.index += 1;
+
+        let fields = &mut self.fstrings.last_mut().unwrap().fields;
+        Box::new(match ch {
+            ':' => {
+                fields.last_mut().unwrap().1 = true;
+                Token::ColonToken(s, e, trivia)
+            },
+            '!' => Token::ExclamationToken(s, e, trivia),
+            _ => {
+                fields.pop();
+                self.brackets.pop();
+                Token::RightCurlyBracketToken(s, e, trivia)
+            }
+        })
+    }
+
+    /// True while the innermost f-string is outside any replacement field or in a format spec.
+    fn is_in_fstring_text(&self) -> bool {
+        match self.fstrings.last() {
+            Some(mode) => mode.fields.last().is_none_or(|field| field.1),
+            None => false
+        }
+    }
+
+    /// True when in the expression of a replacement field and outside any nested brackets.
+    fn is_at_fstring_field(&self) -> bool {
+        match self.fstrings.last().and_then(|mode| mode.fields.last()) {
+            Some((depth, in_format_spec)) => !in_format_spec && *depth == self.brackets.len(),
+            None => false
+        }
+    }
+
+    fn report_unterminated_fstring(&mut self, mode: &FStringMode) {
+        let kind = if mode.is_template { "t-string" } else { "f-string" };
+        let message = match mode.is_triple {
+            true => format!("unterminated triple-quoted {} literal", kind),
+            _ => format!("unterminated {} literal", kind)
+        };
+        self.errors.push(Box::new(Diagnostic::error(DiagnosticCode::UnterminatedString, mode.start as u32, self.index as u32, format!("{} (detected at line {})", message, self.line_number(self.index)))))
+    }
+
+    /// One based line number of an offset, counting '\n', '\r\n' and '\r' as line breaks.
+    fn line_number(&self, offset: usize) -> usize {
+        let source = &self.source[..offset.min(self.source.len())];
+        1 + source.iter().enumerate().filter(|(index, ch)| {
+            **ch == '\n' || (**ch == '\r' && source.get(index + 1) != Some(&'\n'))
+        }).count()
+    }
+}
+
+/// PEP 3131 identifiers start with '_' or a character with the XID_Start property.
+fn is_identifier_start(ch: char) -> bool {
+    ch == '_' || unicode_ident::is_xid_start(ch)
+}
+
+fn is_identifier_continue(ch: char) -> bool {
+    unicode_ident::is_xid_continue(ch)
+}
+
+/// Message for a character that cannot start any token. Characters that would not show up in
+/// an editor, like a non-breaking space, are only given by their code point.
+fn invalid_character_message(ch: char) -> String {
+    let is_format = matches!(ch, '\u{ad}' | '\u{200b}'..='\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2060}'..='\u{2064}' | '\u{feff}');
+
+    match ch.is_whitespace() || ch.is_control() || is_format {
+        true => format!("invalid non-printable character U+{:04X}", ch as u32),
+        _ => format!("invalid character '{}' (U+{:04X})", ch, ch as u32)
+    }
+}
+
+/// Tag of a type ignore comment from the text after 'type:', as '[attr]' in
+/// '# type: ignore[attr]', or None for other type comments.
+fn type_ignore_tag(rest: &str) -> Option<String> {
+    let tag = rest.strip_prefix("ignore")?;
+    match tag.chars().next() {
+        Some(ch) if is_identifier_continue(ch) => None,
+        _ => Some(tag.to_string())
+    }
+}
+
+/// Prefixes starting an f-string, in any case.
+fn is_fstring_prefix(text: &str) -> bool {
+    matches!(text.to_lowercase().as_str(), "f" | "rf" | "fr")
+}
+
+/// Prefixes starting a template string, in any case.
+fn is_tstring_prefix(text: &str) -> bool {
+    matches!(text.to_lowercase().as_str(), "t" | "rt" | "tr")
+}
+
+/// Prefixes allowed in front of a plain or bytes string literal, in any case.
+fn is_string_prefix(text: &str) -> bool {
+    matches!(text.to_lowercase().as_str(), "r" | "u" | "b" | "rb" | "br")
+}
+
+/// Maps reserved keywords to their token, handing the trivia back for anything else.
+fn keyword(text: &str, s: u32, e: u32, trivia: Vec<Box<Trivia>>) -> Result<Token, Vec<Box<Trivia>>> {
+    Ok(match text {
+        "False" => Token::FalseToken(s, e, trivia),
+        "True" => Token::TrueToken(s, e, trivia),
+        "None" => Token::NoneToken(s, e, trivia),
+        "and" => Token::AndToken(s, e, trivia),
+        "as" => Token::AsToken(s, e, trivia),
+        "assert" => Token::AssertToken(s, e, trivia),
+        "async" => Token::AsyncToken(s, e, trivia),
+        "await" => Token::AwaitToken(s, e, trivia),
+        "break" => Token::BreakToken(s, e, trivia),
+        "class" => Token::ClassToken(s, e, trivia),
+        "continue" => Token::ContinueToken(s, e, trivia),
+        "def" => Token::DefToken(s, e, trivia),
+        "del" => Token::DelToken(s, e, trivia),
+        "elif" => Token::ElifToken(s, e, trivia),
+        "else" => Token::ElseToken(s, e, trivia),
+        "except" => Token::ExceptToken(s, e, trivia),
+        "finally" => Token::FinallyToken(s, e, trivia),
+        "for" => Token::ForToken(s, e, trivia),
+        "from" => Token::FromToken(s, e, trivia),
+        "global" => Token::GlobalToken(s, e, trivia),
+        "if" => Token::IfToken(s, e, trivia),
+        "import" => Token::ImportToken(s, e, trivia),
+        "in" => Token::InToken(s, e, trivia),
+        "is" => Token::IsToken(s, e, trivia),
+        "lambda" => Token::LambdaToken(s, e, trivia),
+        "nonlocal" => Token::NonlocalToken(s, e, trivia),
+        "not" => Token::NotToken(s, e, trivia),
+        "or" => Token::OrToken(s, e, trivia),
+        "pass" => Token::PassToken(s, e, trivia),
+        "raise" => Token::RaiseToken(s, e, trivia),
+        "return" => Token::ReturnToken(s, e, trivia),
+        "try" => Token::TryToken(s, e, trivia),
+        "while" => Token::WhileToken(s, e, trivia),
+        "with" => Token::WithToken(s, e, trivia),
+        "yield" => Token::YieldToken(s, e, trivia),
+        _ => return Err(trivia)
+    })
+}
+
+/// Maps operators and delimiters to their token, handing the trivia back for anything else.
+fn operator_or_delimiter(text: &str, s: u32, e: u32, trivia: Vec<Box<Trivia>>) -> Result<Token, Vec<Box<Trivia>>> {
+    Ok(match text {
+        "**=" => Token::PowerAssignToken(s, e, trivia),
+        "//=" => Token::FloorDivideAssignToken(s, e, trivia),
+        "<<=" => Token::ShiftLeftAssignToken(s, e, trivia),
+        ">>=" => Token::ShiftRightAssignToken(s, e, trivia),
+        "..." => Token::EllipsisToken(s, e, trivia),
+        "**" => Token::PowerToken(s, e, trivia),
+        "//" => Token::FloorDivideToken(s, e, trivia),
+        "<<" => Token::ShiftLeftToken(s, e, trivia),
+        ">>" => Token::ShiftRightToken(s, e, trivia),
+        "<=" => Token::LessOrEqualToken(s, e, trivia),
+        ">=" => Token::GreaterOrEqualToken(s, e, trivia),
+        "==" => Token::EqualToken(s, e, trivia),
+        "!=" => Token::NotEqualToken(s, e, trivia),
+        "->" => Token::ArrowToken(s, e, trivia),
+        ":=" => Token::ColonAssignToken(s, e, trivia),
+        "+=" => Token::PlusAssignToken(s, e, trivia),
+        "-=" => Token::MinusAssignToken(s, e, trivia),
+        "*=" => Token::MultiplyAssignToken(s, e, trivia),
+        "/=" => Token::DivideAssignToken(s, e, trivia),
+        "%=" => Token::ModuloAssignToken(s, e, trivia),
+        "@=" => Token::MatricesAssignToken(s, e, trivia),
+        "&=" => Token::AndAssignToken(s, e, trivia),
+        "|=" => Token::OrAssignToken(s, e, trivia),
+        "^=" => Token::XorAssignToken(s, e, trivia),
+        "+" => Token::PlusToken(s, e, trivia),
+        "-" => Token::MinusToken(s, e, trivia),
+        "*" => Token::MultiplyToken(s, e, trivia),
+        "/" => Token::DivideToken(s, e, trivia),
+        "%" => Token::ModuloToken(s, e, trivia),
+        "@" => Token::MatricesToken(s, e, trivia),
+        "&" => Token::BitAndToken(s, e, trivia),
+        "|" => Token::BitOrToken(s, e, trivia),
+        "^" => Token::BitXorToken(s, e, trivia),
+        "~" => Token::BitInvertToken(s, e, trivia),
+        "<" => Token::LessThanToken(s, e, trivia),
+        ">" => Token::GreaterThanToken(s, e, trivia),
+        "(" => Token::LeftParenToken(s, e, trivia),
+        ")" => Token::RightParenToken(s, e, trivia),
+        "[" => Token::LeftSquareBracketToken(s, e, trivia),
+        "]" => Token::RightSquareBracketToken(s, e, trivia),
+        "{" => Token::LeftCurlyBracketToken(s, e, trivia),
+        "}" => Token::RightCurlyBracketToken(s, e, trivia),
+        "," => Token::CommaToken(s, e, trivia),
+        ":" => Token::ColonToken(s, e, trivia),
+        ";" => Token::SemicolonToken(s, e, trivia),
+        "." => Token::PeriodToken(s, e, trivia),
+        "=" => Token::AssignToken(s, e, trivia),
+        _ => return Err(trivia)
+    })
+}
+
+// Unittests for tokenizer /////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::parser::parser_options::ParserOptions;
+    use crate::parser::python_core_tokenizer::{LexerMethods, PythonCoreTokenizer};
+    use crate::parser::python_version::PythonVersion;
+    use crate::parser::diagnostic::{Diagnostic, DiagnosticCode};
+    use crate::parser::token_nodes::Token;
+    use crate::parser::trivia_nodes::Trivia;
+
+    fn tokenize(source: &str) -> Vec<Box<Token>> {
+        let mut lexer = PythonCoreTokenizer::new(source);
+        let mut symbols = Vec::<Box<Token>>::new();
+
+        loop {
+            lexer.advance();
+            let symbol = lexer.symbol.clone();
+            let is_eof = matches!(&*symbol, Token::EofToken( _ , _ , _ ));
+            symbols.push(symbol);
+            if is_eof {
+                return symbols
+            }
+        }
+    }
+
+    fn tokenize_errors(source: &str) -> Vec<Box<Diagnostic>> {
+        let mut lexer = PythonCoreTokenizer::new(source);
+
+        loop {
+            lexer.advance();
+            if let Token::EofToken( _ , _ , _ ) = &*lexer.symbol {
+                return lexer.errors
+            }
+        }
+    }
+
+    #[test]
+    fn tokenize_keywords_and_names() {
+        let symbols = tokenize("if x is not None: pass");
+
+        assert!(matches!(&*symbols[0], Token::IfToken(0, 2, _)));
+        assert!(matches!(&*symbols[1], Token::NameToken(3, 4, text, _) if text.as_str() == "x"));
+        assert!(matches!(&*symbols[2], Token::IsToken(5, 7, _)));
+        assert!(matches!(&*symbols[3], Token::NotToken(8, 11, _)));
+        assert!(matches!(&*symbols[4], Token::NoneToken(12, 16, _)));
+        assert!(matches!(&*symbols[5], Token::ColonToken(16, 17, _)));
+        assert!(matches!(&*symbols[6], Token::PassToken(18, 22, _)));
+        assert!(matches!(&*symbols[7], Token::NewlineToken(22, 22, _ , _ , _)));
+        assert!(matches!(&*symbols[8], Token::EofToken(22, 22, _)));
+    }
+
+    #[test]
+    fn tokenize_longest_operator_first() {
+        let symbols = tokenize("a **= b // c ... -> :=");
+
+        assert!(matches!(&*symbols[1], Token::PowerAssignToken(2, 5, _)));
+        assert!(matches!(&*symbols[3], Token::FloorDivideToken(8, 10, _)));
+        assert!(matches!(&*symbols[5], Token::EllipsisToken(13, 16, _)));
+        assert!(matches!(&*symbols[6], Token::ArrowToken(17, 19, _)));
+        assert!(matches!(&*symbols[7], Token::ColonAssignToken(20, 22, _)));
+    }
+
+    #[test]
+    fn tokenize_numbers_and_strings() {
+        let symbols = tokenize("0xff 1.5e-3j .5 'it\\'s' \"x\"");
+
+        assert!(matches!(&*symbols[0], Token::NumberToken(0, 4, text, _) if text.as_str() == "0xff"));
+        assert!(matches!(&*symbols[1], Token::NumberToken(5, 12, text, _) if text.as_str() == "1.5e-3j"));
+        assert!(matches!(&*symbols[2], Token::NumberToken(13, 15, _, _)));
+        assert!(matches!(&*symbols[3], Token::StringToken(16, 23, _, _)));
+        assert!(matches!(&*symbols[4], Token::StringToken(24, 27, _, _)));
+    }
+
+    #[test]
+    fn tokenize_number_literals() {
+        let numbers = ["1_000", "0", "00", "0_0", "0x_ff", "0O17", "0b1_0", "3.14", "1.", ".5", "1e10", "1_0.0_1E-1_0", "09.5", "07j", "1.5J", "0e0"];
+
+        for number in numbers {
+            let symbols = tokenize(number);
+            assert!(matches!(&*symbols[0], Token::NumberToken(0, _, text, _) if text.as_str() == number));
+            assert!(matches!(&*symbols[1], Token::NewlineToken( _ , _ , _ , _ , _ )));
+            assert!(tokenize_errors(number).is_empty());
+        }
+    }
+
+    #[test]
+    fn tokenize_invalid_number_literals() {
+        let cases = [
+            ("0777", 0, "leading zeros in decimal integer literals are not permitted; use an 0o prefix for octal integers"),
+            ("0_7", 0, "leading zeros in decimal integer literals are not permitted; use an 0o prefix for octal integers"),
+            ("1__0", 1, "invalid decimal literal"),
+            ("1_", 1, "invalid decimal literal"),
+            ("1_.5", 1, "invalid decimal literal"),
+            ("0x", 0, "invalid hexadecimal literal"),
+            ("0xfg", 3, "invalid hexadecimal literal"),
+            ("0x_", 0, "invalid hexadecimal literal"),
+            ("0o18", 3, "invalid digit '8' in octal literal"),
+            ("0b2", 2, "invalid digit '2' in binary literal"),
+            ("1abc", 1, "invalid decimal literal"),
+            ("1jx", 2, "invalid imaginary literal"),
+        ];
+
+        for (source, position, message) in cases {
+            let errors = tokenize_errors(source);
+            assert_eq!(errors.len(), 1, "{}", source);
+            assert_eq!(errors[0].start, position, "{}", source);
+            assert_eq!(errors[0].message, message, "{}", source);
+
+            let symbols = tokenize(source);
+            assert!(matches!(&*symbols[0], Token::BadToken(0, _, text, _) if text.as_str() == source), "{}", source);
+        }
+    }
+
+    #[test]
+    fn tokenize_number_followed_by_keyword() {
+        let mut lexer = PythonCoreTokenizer::new("1if x else 0x1or y");
+        let mut symbols = Vec::<Box<Token>>::new();
+
+        loop {
+            lexer.advance();
+            symbols.push(lexer.symbol.clone());
+            if let Token::EofToken( _ , _ , _ ) = &*lexer.symbol {
+                break
+            }
+        }
+
+        assert!(lexer.errors.is_empty());
+        assert_eq!(lexer.warnings.len(), 2);
+        assert_eq!(lexer.warnings[0].start, 1);
+        assert_eq!(lexer.warnings[0].message, "invalid decimal literal");
+        assert_eq!(lexer.warnings[1].message, "invalid hexadecimal literal");
+        assert!(matches!(&*symbols[0], Token::NumberToken(0, 1, _, _)));
+        assert!(matches!(&*symbols[1], Token::IfToken(1, 3, _)));
+        assert!(matches!(&*symbols[5], Token::OrToken(14, 16, _)));
+
+        let symbols = tokenize("1else");
+        assert!(matches!(&*symbols[0], Token::NumberToken(0, 1, _, _)));
+        assert!(matches!(&*symbols[1], Token::ElseToken(1, 5, _)));
+    }
+
+    #[test]
+    fn tokenize_string_prefixes_and_quotes() {
+        let strings = ["'a'", "\"a\"", "r'\\d'", "U'x'", "b'x'", "Rb'x'", "bR\"x\"", "'it\\'s'", "r'\\''", "'''a ' '' \n b'''", "\"\"\"\"\"\"", "'a\\\nb'"];
+
+        for string in strings {
+            let symbols = tokenize(string);
+            assert!(matches!(&*symbols[0], Token::StringToken(0, _, parts, _) if parts[0].as_str() == string), "{}", string);
+            assert!(matches!(&*symbols[1], Token::NewlineToken( _ , _ , _ , _ , _ )), "{}", string);
+            assert!(tokenize_errors(string).is_empty(), "{}", string);
+        }
+
+        let symbols = tokenize("ur'x' f 'y' \"z\"");
+        assert!(matches!(&*symbols[0], Token::NameToken(0, 2, _, _)));
+        assert!(matches!(&*symbols[1], Token::StringToken(2, 5, _, _)));
+        assert!(matches!(&*symbols[2], Token::NameToken(6, 7, _, _)));
+        assert!(matches!(&*symbols[3], Token::StringToken(8, 11, _, _)));
+        assert!(matches!(&*symbols[4], Token::StringToken(12, 15, _, _)));
+    }
+
+    #[test]
+    fn tokenize_unterminated_strings() {
+        let errors = tokenize_errors("x = 'abc\ny = 1\n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 4);
+        assert_eq!(errors[0].message, "unterminated string literal (detected at line 1)");
+
+        let symbols = tokenize("x = 'abc\ny = 1\n");
+        assert!(matches!(&*symbols[2], Token::BadToken(4, 8, text, _) if text.as_str() == "'abc"));
+        assert!(matches!(&*symbols[3], Token::NewlineToken(8, 9, _ , _ , _)));
+        assert!(matches!(&*symbols[4], Token::NameToken(9, 10, _ , _ )));
+
+        let errors = tokenize_errors("x = 1\ns = b'''abc\n\ndef");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 11);
+        assert_eq!(errors[0].message, "unterminated triple-quoted string literal (detected at line 4)");
+
+        let errors = tokenize_errors("b'caf\u{e9}'");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 5);
+        assert_eq!(errors[0].message, "bytes can only contain ASCII literal characters");
+    }
+
+    #[test]
+    fn tokenize_fstring_parts() {
+        let symbols = tokenize("f'a{x!r:>{w}}b{{c}}' rf\"\\{y = }\"");
+
+        assert!(matches!(&*symbols[0], Token::FStringStartToken(0, 2, text, _) if text.as_str() == "f'"));
+        assert!(matches!(&*symbols[1], Token::FStringMiddleToken(2, 3, text, _) if text.as_str() == "a"));
+        assert!(matches!(&*symbols[2], Token::LeftCurlyBracketToken(3, 4, _)));
+        assert!(matches!(&*symbols[3], Token::NameToken(4, 5, _, _)));
+        assert!(matches!(&*symbols[4], Token::ExclamationToken(5, 6, _)));
+        assert!(matches!(&*symbols[5], Token::NameToken(6, 7, _, _)));
+        assert!(matches!(&*symbols[6], Token::ColonToken(7, 8, _)));
+        assert!(matches!(&*symbols[7], Token::FStringMiddleToken(8, 9, text, _) if text.as_str() == ">"));
+        assert!(matches!(&*symbols[8], Token::LeftCurlyBracketToken(9, 10, _)));
+        assert!(matches!(&*symbols[9], Token::NameToken(10, 11, _, _)));
+        assert!(matches!(&*symbols[10], Token::RightCurlyBracketToken(11, 12, _)));
+        assert!(matches!(&*symbols[11], Token::RightCurlyBracketToken(12, 13, _)));
+        assert!(matches!(&*symbols[12], Token::FStringMiddleToken(13, 19, text, _) if text.as_str() == "b{{c}}"));
+        assert!(matches!(&*symbols[13], Token::FStringEndToken(19, 20, _, trivia) if trivia.len() == 1));
+        assert!(matches!(&*symbols[14], Token::FStringStartToken(21, 24, _, _)));
+        assert!(matches!(&*symbols[15], Token::FStringMiddleToken(24, 25, text, _) if text.as_str() == "\\"));
+        assert!(matches!(&*symbols[17], Token::NameToken(26, 27, _, _)));
+        assert!(matches!(&*symbols[18], Token::AssignToken(28, 29, _)));
+        assert!(matches!(&*symbols[19], Token::RightCurlyBracketToken(30, 31, _)));
+        assert!(matches!(&*symbols[20], Token::FStringEndToken(31, 32, _, _)));
+        assert!(matches!(&*symbols[21], Token::NewlineToken( _ , _ , _ , _ , _ )));
+    }
+
+    #[test]
+    fn tokenize_nested_fstrings() {
+        let symbols = tokenize("f\"{f\"{x:{'y'}}\"!s}\"");
+
+        assert!(matches!(&*symbols[0], Token::FStringStartToken(0, 2, _, _)));
+        assert!(matches!(&*symbols[2], Token::FStringStartToken(3, 5, _, _)));
+        assert!(matches!(&*symbols[6], Token::LeftCurlyBracketToken(8, 9, _)));
+        assert!(matches!(&*symbols[7], Token::StringToken(9, 12, _, _)));
+        assert!(matches!(&*symbols[10], Token::FStringEndToken(14, 15, _, _)));
+        assert!(matches!(&*symbols[11], Token::ExclamationToken(15, 16, _)));
+        assert!(matches!(&*symbols[14], Token::FStringEndToken(18, 19, _, _)));
+        assert!(tokenize_errors("f\"{f\"{x:{'y'}}\"!s}\"").is_empty());
+
+        let symbols = tokenize("f'{a!=b}{x:=5}{d[\"k\"]:{n}}'");
+        assert!(matches!(&*symbols[3], Token::NotEqualToken(4, 6, _)));
+        assert!(matches!(&*symbols[8], Token::ColonToken(10, 11, _)));
+        assert!(matches!(&*symbols[9], Token::FStringMiddleToken(11, 13, text, _) if text.as_str() == "=5"));
+        assert!(matches!(&*symbols[15], Token::RightSquareBracketToken(20, 21, _)));
+        assert!(matches!(&*symbols[16], Token::ColonToken(21, 22, _)));
+    }
+
+    #[test]
+    fn tokenize_fstring_errors() {
+        let errors = tokenize_errors("x = f'abc\ny = 1\n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 5);
+        assert_eq!(errors[0].message, "unterminated f-string literal (detected at line 1)");
+
+        let errors = tokenize_errors("x = f'''{a}\n\n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 5);
+        assert_eq!(errors[0].message, "unterminated triple-quoted f-string literal (detected at line 3)");
+
+        let errors = tokenize_errors("f'a}b'");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 3);
+        assert_eq!(errors[0].message, "f-string: single '}' is not allowed");
+
+        let errors = tokenize_errors("f'{x:abc'");
+        assert_eq!(errors[0].start, 8);
+        assert_eq!(errors[0].message, "f-string: expecting '}'");
+    }
+
+    #[test]
+    fn tokenize_template_strings() {
+        let symbols = tokenize("t'a{b}' Rt\"{c!r}\" tr'''x'''");
+
+        assert!(matches!(&*symbols[0], Token::TStringStartToken(0, 2, text, _) if text.as_str() == "t'"));
+        assert!(matches!(&*symbols[1], Token::TStringMiddleToken(2, 3, _, _)));
+        assert!(matches!(&*symbols[5], Token::TStringEndToken(6, 7, _, _)));
+        assert!(matches!(&*symbols[6], Token::TStringStartToken(8, 11, _, _)));
+        assert!(matches!(&*symbols[12], Token::TStringEndToken(16, 17, _, _)));
+        assert!(matches!(&*symbols[13], Token::TStringStartToken(18, 23, _, _)));
+        assert!(matches!(&*symbols[14], Token::TStringMiddleToken(23, 24, _, _)));
+        assert!(matches!(&*symbols[15], Token::TStringEndToken(24, 27, _, _)));
+        assert!(tokenize_errors("t'a{b}' Rt\"{c!r}\" tr'''x'''").is_empty());
+
+        let mut lexer = PythonCoreTokenizer::with_target("x = t'{y}'\n", PythonVersion::Python313);
+        loop {
+            lexer.advance();
+            if let Token::EofToken( _ , _ , _ ) = &*lexer.symbol {
+                break
+            }
+        }
+        assert_eq!(lexer.errors.len(), 1);
+        assert_eq!(lexer.errors[0].start, 4);
+        assert_eq!(lexer.errors[0].message, "template strings are only supported in Python 3.14 and greater, target is Python 3.13");
+
+        let errors = tokenize_errors("t'abc\n");
+        assert_eq!(errors[0].message, "unterminated t-string literal (detected at line 1)");
+    }
+
+    #[test]
+    fn tokenize_unicode_identifiers() {
+        let symbols = tokenize("ﬁle = café_π + Δx1 + _ñ");
+
+        assert!(matches!(&*symbols[0], Token::NameToken(0, 3, text, _) if text.as_str() == "ﬁle"));
+        assert!(matches!(&*symbols[2], Token::NameToken(6, 12, text, _) if text.as_str() == "café_π"));
+        assert!(matches!(&*symbols[4], Token::NameToken(15, 18, _, _)));
+        assert!(matches!(&*symbols[6], Token::NameToken(21, 23, _, _)));
+        assert!(tokenize_errors("ﬁle = café_π + Δx1 + _ñ").is_empty());
+
+        assert_eq!(symbols[0].normalized_name(), Some(String::from("file")));
+        assert_eq!(tokenize("file")[0].normalized_name(), symbols[0].normalized_name());
+        assert_eq!(symbols[1].normalized_name(), None);
+    }
+
+    #[test]
+    fn tokenize_invalid_unicode_characters() {
+        let errors = tokenize_errors("price = 5€");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 9);
+        assert_eq!(errors[0].message, "invalid character '€' (U+20AC)");
+
+        let errors = tokenize_errors("a =\u{a0}1");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 3);
+        assert_eq!(errors[0].message, "invalid non-printable character U+00A0");
+
+        let symbols = tokenize("a =\u{a0}1");
+        assert!(matches!(&*symbols[2], Token::BadToken(3, 4, text, _) if text.as_str() == "\u{a0}"));
+        assert!(matches!(&*symbols[3], Token::NumberToken(4, 5, _, _)));
+    }
+
+    #[test]
+    fn tokenize_bytes_with_bom_and_coding_declaration() {
+        let mut lexer = PythonCoreTokenizer::from_bytes(b"\xef\xbb\xbf#!/usr/bin/python\n# -*- coding: utf-8 -*-\nx = '\xc3\xa9'\n", &ParserOptions::default());
+        lexer.advance();
+
+        assert!(matches!(&*lexer.symbol, Token::NameToken(43, 44, _, _)));
+        let trivia = lexer.symbol.leading_trivia();
+        assert!(matches!(trivia[0], Trivia::ByteOrderMark(0, 1)));
+        assert!(matches!(trivia[1], Trivia::Comment(1, 18, _)));
+        assert!(matches!(trivia[3], Trivia::EncodingDeclaration(19, 42, text) if text.as_str() == "# -*- coding: utf-8 -*-"));
+        assert!(lexer.errors.is_empty());
+
+        let mut lexer = PythonCoreTokenizer::from_bytes(b"# coding: latin-1\ns = '\xe9'\n", &ParserOptions::default());
+        let mut text = String::new();
+        loop {
+            lexer.advance();
+            text.push_str(&lexer.symbol.full_text());
+            if let Token::EofToken( _ , _ , _ ) = &*lexer.symbol {
+                break
+            }
+        }
+        assert_eq!(text, "# coding: latin-1\ns = '\u{e9}'\n");
+        assert!(lexer.errors.is_empty());
+    }
+
+    #[test]
+    fn tokenize_bytes_with_invalid_utf8() {
+        let mut lexer = PythonCoreTokenizer::from_bytes(b"s = '\xc3\xa9\xc3'\n", &ParserOptions::default());
+        loop {
+            lexer.advance();
+            if let Token::EofToken( _ , _ , _ ) = &*lexer.symbol {
+                break
+            }
+        }
+
+        assert_eq!(lexer.errors.len(), 1);
+        assert_eq!(lexer.errors[0].start, 6);
+        assert_eq!(lexer.errors[0].message, "'utf-8' codec can't decode byte 0xc3 in position 7: invalid continuation byte");
+    }
+
+    #[test]
+    fn tokenize_indent_and_dedent() {
+        let symbols = tokenize("if a:\n    if b:\n        pass\n\n# comment\nx\n");
+
+        assert!(matches!(&*symbols[3], Token::NewlineToken(5, 6, '\n', '\0', _)));
+        assert!(matches!(&*symbols[4], Token::IndentToken(10, 10, _)));
+        assert!(matches!(&*symbols[9], Token::IndentToken(24, 24, _)));
+        assert!(matches!(&*symbols[10], Token::PassToken(24, 28, _)));
+        assert!(matches!(&*symbols[11], Token::NewlineToken(28, 29, _ , _ , _)));
+        assert!(matches!(&*symbols[12], Token::DedentToken(40, 40, _)));
+        assert!(matches!(&*symbols[13], Token::DedentToken(40, 40, _)));
+        assert!(matches!(&*symbols[14], Token::NameToken(40, 41, _, _)));
+        assert!(matches!(&*symbols[15], Token::NewlineToken(41, 42, _ , _ , _)));
+        assert!(matches!(&*symbols[16], Token::EofToken(42, 42, _)));
+    }
+
+    #[test]
+    fn tokenize_dedent_at_end_of_file() {
+        let symbols = tokenize("def f():\r\n    return 1");
+
+        assert!(matches!(&*symbols[5], Token::NewlineToken(8, 10, '\r', '\n', _)));
+        assert!(matches!(&*symbols[6], Token::IndentToken(14, 14, _)));
+        assert!(matches!(&*symbols[9], Token::NewlineToken(22, 22, _ , _ , _)));
+        assert!(matches!(&*symbols[10], Token::DedentToken(22, 22, _)));
+        assert!(matches!(&*symbols[11], Token::EofToken(22, 22, _)));
+    }
+
+    #[test]
+    fn tokenize_multiple_dedents_and_levels() {
+        let mut lexer = PythonCoreTokenizer::new("if a:\n  if b:\n      x\n  y\nz\n");
+        let mut dedents = 0;
+
+        loop {
+            lexer.advance();
+            match &*lexer.symbol {
+                Token::DedentToken( _ , _ , _ ) => dedents += 1,
+                Token::EofToken( _ , _ , _ ) => break,
+                _ => ()
+            }
+        }
+
+        assert_eq!(dedents, 2);
+        assert!(lexer.errors.is_empty());
+    }
+
+    #[test]
+    fn tokenize_unindent_not_matching_outer_level() {
+        let errors = tokenize_errors("if a:\n    x\n  y\n");
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 14);
+        assert_eq!(errors[0].message, "unindent does not match any outer indentation level");
+    }
+
+    #[test]
+    fn tokenize_inconsistent_tabs_and_spaces() {
+        let errors = tokenize_errors("if a:\n\tx\n        y\n");
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 17);
+        assert_eq!(errors[0].message, "inconsistent use of tabs and spaces in indentation");
+    }
+
+    #[test]
+    fn tokenize_consistent_tabs() {
+        let errors = tokenize_errors("if a:\n\tif b:\n\t\tx\n\ty\n");
+
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn tokenize_implicit_line_joining() {
+        let symbols = tokenize("f(a,\n    [b,\n\n     c])\nx\n");
+
+        assert!(matches!(&*symbols[4], Token::LeftSquareBracketToken(9, 10, _)));
+        assert_eq!(symbols[6].trailing_trivia().len(), 1);
+        assert!(matches!(symbols[6].trailing_trivia()[0], Trivia::Newline(12, 13, '\n', '\0')));
+        assert!(matches!(&*symbols[7], Token::NameToken(19, 20, _, _)));
+        assert_eq!(symbols[7].leading_trivia().len(), 2);
+        assert!(matches!(symbols[7].leading_trivia()[0], Trivia::Newline(13, 14, '\n', '\0')));
+        assert!(matches!(&*symbols[10], Token::NewlineToken(22, 23, _ , _ , _)));
+        assert!(matches!(&*symbols[11], Token::NameToken(23, 24, _, _)));
+        assert!(tokenize_errors("f(a,\n    [b,\n\n     c])\nx\n").is_empty());
+    }
+
+    #[test]
+    fn tokenize_explicit_line_joining() {
+        let symbols = tokenize("x = 1 + \\\n        2\n");
+
+        assert!(matches!(symbols[3].trailing_trivia()[1], Trivia::LineContinuation(8, 10, '\n', '\0')));
+        assert!(matches!(&*symbols[4], Token::NumberToken(18, 19, _, _)));
+        assert!(matches!(symbols[4].leading_trivia()[0], Trivia::Whitespace(10, 18, _)));
+        assert!(matches!(&*symbols[5], Token::NewlineToken(19, 20, _ , _ , _)));
+        assert!(matches!(&*symbols[6], Token::EofToken(20, 20, _)));
+    }
+
+    #[test]
+    fn tokenize_line_continuation_errors() {
+        let errors = tokenize_errors("x = 1 \\ + 2\n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 6);
+        assert_eq!(errors[0].message, "unexpected character after line continuation character");
+
+        let errors = tokenize_errors("x = 1 + \\");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 8);
+        assert_eq!(errors[0].message, "unexpected EOF while parsing");
+    }
+
+    #[test]
+    fn tokenize_unbalanced_brackets() {
+        let errors = tokenize_errors("(a]\n");
+        assert_eq!(errors[0].message, "closing parenthesis ']' does not match opening parenthesis '('");
+        assert_eq!(errors[0].code, DiagnosticCode::UnmatchedBracket);
+        assert_eq!((errors[0].start, errors[0].end), (2, 3));
+        assert_eq!((errors[0].labels[0].start, errors[0].labels[0].end), (0, 1));
+        assert_eq!(errors[0].labels[0].message, "opening parenthesis here");
+
+        let errors = tokenize_errors("a)\n");
+        assert_eq!(errors[0].start, 1);
+        assert_eq!(errors[0].message, "unmatched ')'");
+
+        let errors = tokenize_errors("x = [1,\n2\n");
+        assert_eq!(errors[0].start, 4);
+        assert_eq!(errors[0].message, "'[' was never closed");
+    }
+
+    #[test]
+    fn tokenize_invalid_character() {
+        let symbols = tokenize("a $ b");
+
+        assert!(matches!(&*symbols[1], Token::BadToken(2, 3, text, _) if text.as_str() == "$"));
+        assert!(matches!(symbols[1].trailing_trivia()[0], Trivia::Whitespace(3, 4, _)));
+        assert!(matches!(&*symbols[2], Token::NameToken(4, 5, _, _)));
+
+        let errors = tokenize_errors("a $ b");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].start, 2);
+        assert_eq!(errors[0].message, "invalid character '$' (U+0024)");
+    }
+
+    #[test]
+    fn tokenize_leading_and_trailing_trivia() {
+        let symbols = tokenize("# header
+
+x = 1  # one
+");
+
+        assert!(matches!(&*symbols[0], Token::NameToken(10, 11, _, _)));
+        assert_eq!(symbols[0].leading_trivia().len(), 3);
+        assert!(matches!(symbols[0].leading_trivia()[0], Trivia::Comment(0, 8, text) if text.as_str() == "# header"));
+        assert!(matches!(symbols[0].leading_trivia()[2], Trivia::Newline(9, 10, '\n', '\0')));
+        assert!(matches!(symbols[0].trailing_trivia()[0], Trivia::Whitespace(11, 12, _)));
+        assert_eq!(symbols[2].trailing_trivia().len(), 2);
+        assert!(matches!(symbols[2].trailing_trivia()[1], Trivia::Comment(17, 22, _)));
+        assert!(symbols[3].trivia().is_empty());
+    }
+
+    #[test]
+    fn tokenize_reproduces_source_text() {
+        let sources = [
+            "if a:\n    if b:\n        pass\n\n# comment\nx\n",
+            "def f():\r\n\treturn [1,  # one\r\n\r\n  2]\r\n",
+            "x = 1 + \\\n        2 \x0c# tail\n  \n",
+            "a $ b\\ c\n",
+            "f'''{\n  a # comment\n  !r:{ w }} {{}}''' F'{ x = }' f'open\n",
+            "s = '''one\r\ntwo''' rb'\\'' 'open\n",
+            "x = (\n",
+            "y = 'abc'  \\",
+        ];
+
+        for source in sources {
+            let text: String = tokenize(source).iter().map(|symbol| symbol.full_text()).collect();
+            assert_eq!(text, source);
+        }
+    }
+
+    #[test]
+    fn peek_ahead_of_current_symbol() {
+        let mut lexer = PythonCoreTokenizer::new("match (x):\n    pass\n");
+        lexer.advance();
+
+        assert!(matches!(lexer.peek(0), Token::NameToken(0, 5, _, _)));
+        assert!(matches!(lexer.peek(2), Token::NameToken(7, 8, _, _)));
+        assert!(matches!(lexer.peek(1), Token::LeftParenToken(6, 7, _)));
+        assert!(matches!(lexer.peek(4), Token::ColonToken(9, 10, _)));
+        assert!(matches!(lexer.peek(42), Token::EofToken(20, 20, _)));
+
+        lexer.advance();
+        assert!(matches!(&*lexer.symbol, Token::LeftParenToken(6, 7, _)));
+        assert_eq!(lexer.position, 6);
+        assert!(matches!(lexer.peek(3), Token::ColonToken(9, 10, _)));
+
+        let rest: Vec<Token> = lexer.collect();
+        assert_eq!(rest.len(), 9);
+        assert!(matches!(&rest[3], Token::NewlineToken( .. )));
+        assert!(matches!(&rest[8], Token::EofToken(20, 20, _)));
+    }
+
+    #[test]
+    fn iterate_tokens() {
+        let source = "x = f'{a!r}'  # note\n";
+        let symbols: Vec<Token> = PythonCoreTokenizer::new(source).collect();
+
+        assert_eq!(symbols.len(), tokenize(source).len());
+        assert!(matches!(symbols.last(), Some(Token::EofToken( .. ))));
+        assert_eq!(symbols.iter().map(|symbol| symbol.full_text()).collect::<String>(), source);
+
+        let names = PythonCoreTokenizer::new("a + b * c\n").filter(|symbol| matches!(symbol, Token::NameToken( .. ))).count();
+        assert_eq!(names, 3);
+    }
+
+    #[test]
+    fn tokenize_past_bad_tokens() {
+        let source = "x = 0777\ny = 'ab\nz = a € b\nw = 1\n";
+        let symbols = tokenize(source);
+
+        let bad: Vec<(u32, u32, String)> = symbols.iter().filter_map(|symbol| match &**symbol {
+            Token::BadToken(s, e, text, _) => Some((*s, *e, text.to_string())),
+            _ => None
+        }).collect();
+        assert_eq!(bad, vec![(4, 8, String::from("0777")), (13, 16, String::from("'ab")), (23, 24, String::from("€"))]);
+
+        let errors = tokenize_errors(source);
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors.iter().map(|error| error.start).collect::<Vec<u32>>(), vec![4, 13, 23]);
+
+        assert!(matches!(&*symbols[symbols.len() - 3], Token::NumberToken(31, 32, _, _)));
+        assert_eq!(symbols.iter().map(|symbol| symbol.full_text()).collect::<String>(), source);
+    }
+
+    #[test]
+    fn tokenize_type_comments() {
+        let source = "x = []  # type: List[int]\ndef f():\n    # type: () -> None\n    y = 1  # type: ignore[attr]\n";
+        let mut lexer = PythonCoreTokenizer::new(source);
+        lexer.options.type_comments = true;
+        let symbols: Vec<Token> = lexer.by_ref().collect();
+
+        assert!(matches!(&symbols[4], Token::TypeCommentToken(8, 25, text, _ ) if &**text == "# type: List[int]"));
+        assert!(matches!(&symbols[5], Token::NewlineToken( .. )));
+        assert!(matches!(&symbols[12], Token::TypeCommentToken(39, 57, text, _ ) if &**text == "# type: () -> None"));
+        assert!(matches!(&symbols[13], Token::NewlineToken( .. )));
+        assert!(matches!(&symbols[14], Token::IndentToken( .. )));
+        assert!(!symbols.iter().skip(15).any(|symbol| matches!(symbol, Token::TypeCommentToken( .. ))));
+        assert_eq!(lexer.type_ignores, vec![(69, String::from("[attr]"))]);
+        assert_eq!(symbols.iter().map(|symbol| symbol.full_text()).collect::<String>(), source);
+
+        let symbols = tokenize(source);
+        assert!(!symbols.iter().any(|symbol| matches!(&**symbol, Token::TypeCommentToken( .. ))));
+        assert!(matches!(&*symbols[4], Token::NewlineToken( .. )));
+    }
+}
@@ -0,0 +1,77 @@
+
+/// Python language version the source is parsed for, features newer than it are reported.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum PythonVersion {
+    Python38,
+    Python39,
+    Python310,
+    Python311,
+    Python312,
+    Python313,
+    Python314,
+}
+
+impl PythonVersion {
+    pub const LATEST: PythonVersion = PythonVersion::Python314;
+
+    /// Version as written in messages, i.e. "3.14".
+    pub fn text(&self) -> &'static str {
+        match self {
+            PythonVersion::Python38 => "3.8",
+            PythonVersion::Python39 => "3.9",
+            PythonVersion::Python310 => "3.10",
+            PythonVersion::Python311 => "3.11",
+            PythonVersion::Python312 => "3.12",
+            PythonVersion::Python313 => "3.13",
+            PythonVersion::Python314 => "3.14"
+        }
+    }
+}
+
+/// Language features gated on the target version, they can also be turned on or off one by one.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Feature {
+    ParenthesizedWithItems,
+    PatternMatching,
+    ExceptionGroups,
+    TypeParameters,
+    TypeParameterDefaults,
+    UnparenthesizedExceptTypes,
+    TemplateStrings,
+}
+
+impl Feature {
+    /// First version with the feature.
+    pub fn version(&self) -> PythonVersion {
+        match self {
+            Feature::ParenthesizedWithItems |
+            Feature::PatternMatching => PythonVersion::Python310,
+            Feature::ExceptionGroups => PythonVersion::Python311,
+            Feature::TypeParameters => PythonVersion::Python312,
+            Feature::TypeParameterDefaults => PythonVersion::Python313,
+            Feature::UnparenthesizedExceptTypes |
+            Feature::TemplateStrings => PythonVersion::Python314
+        }
+    }
+
+    /// Feature as written in messages, i.e. "pattern matching".
+    pub fn text(&self) -> &'static str {
+        match self {
+            Feature::ParenthesizedWithItems => "parenthesized context managers",
+            Feature::PatternMatching => "pattern matching",
+            Feature::ExceptionGroups => "exception groups",
+            Feature::TypeParameters => "type parameters and type aliases",
+            Feature::TypeParameterDefaults => "type parameter defaults",
+            Feature::UnparenthesizedExceptTypes => "multiple exception types without parentheses",
+            Feature::TemplateStrings => "template strings"
+        }
+    }
+
+    /// Verb agreeing with the text, "is" or "are".
+    pub fn verb(&self) -> &'static str {
+        match self {
+            Feature::PatternMatching => "is",
+            _ => "are"
+        }
+    }
+}
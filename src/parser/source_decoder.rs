@@ -0,0 +1,239 @@
+use super::diagnostic::{Diagnostic, DiagnosticCode};
+
+/// Source text decoded from the bytes of a file as described in PEP 263.
+pub struct DecodedSource {
+    /// Decoded text, starting with U+FEFF when the file had a UTF-8 byte order mark.
+    pub text: String,
+    /// Normalized name of the encoding used, i.e. "utf-8" or "iso-8859-1".
+    pub encoding: &'static str,
+    /// Decoding problems, positions are char offsets into the decoded text like all other diagnostics.
+    /// Messages give the byte position in the input as CPython does.
+    pub errors: Vec<Box<Diagnostic>>,
+}
+
+/// Decodes Python source from bytes. A UTF-8 byte order mark or a coding declaration on the
+/// first or second line selects the encoding, otherwise UTF-8 is used. Bytes that cannot be
+/// decoded are reported and replaced with U+FFFD.
+pub fn decode_source(source: &[u8]) -> DecodedSource {
+    let mut errors = Vec::<Box<Diagnostic>>::new();
+    let has_bom = source.starts_with(&[0xef, 0xbb, 0xbf]);
+
+    /* The declaration is plain ASCII, Latin-1 keeps byte offsets and char offsets the same */
+    let skipped = if has_bom { 3 } else { 0 };
+    let head: String = source[skipped..].iter().take(1024).map(|b| *b as char).collect();
+    let declared = coding_declaration(&head).map(|(start, end, name)| (start + skipped, end + skipped, name));
+
+    /* Both problems fall back to UTF-8, the declaration is at the char offset of its byte offset in UTF-8 */
+    let encoding = match &declared {
+        Some((start, _ , name)) => match normal_encoding_name(name) {
+            Some("utf-8") => "utf-8",
+            Some(encoding) if has_bom => {
+                let start = String::from_utf8_lossy(&source[..*start]).chars().count() as u32;
+                errors.push(Box::new(Diagnostic::error(DiagnosticCode::Encoding, start, start + 1, format!("encoding problem: {} with BOM", encoding))));
+                "utf-8"
+            },
+            Some(encoding) => encoding,
+            None => {
+                let start = String::from_utf8_lossy(&source[..*start]).chars().count() as u32;
+                errors.push(Box::new(Diagnostic::error(DiagnosticCode::Encoding, start, start + 1, format!("unknown encoding: {}", name))));
+                "utf-8"
+            }
+        },
+        None => "utf-8"
+    };
+
+    let text = match encoding {
+        "ascii" => decode_single_byte(source, "ascii", &mut errors, |b| match b < 0x80 {
+            true => Ok(b as char),
+            _ => Err("ordinal not in range(128)")
+        }),
+        "iso-8859-1" => source.iter().map(|b| *b as char).collect(),
+        "cp1252" => decode_single_byte(source, "charmap", &mut errors, |b| match b {
+            0x80..=0x9f => match CP1252_HIGH[(b - 0x80) as usize] {
+                '\0' => Err("character maps to <undefined>"),
+                ch => Ok(ch)
+            },
+            _ => Ok(b as char)
+        }),
+        _ => decode_utf8(source, &mut errors)
+    };
+
+    DecodedSource { text, encoding, errors }
+}
+
+/// Finds a coding declaration like '# -*- coding: latin-1 -*-' on the first line, or on the
+/// second when the first is blank or a comment. Returns the char span of the comment and the
+/// declared encoding name as written.
+pub fn coding_declaration(text: &str) -> Option<(usize, usize, String)> {
+    let mut offset = 0;
+
+    for line in text.split_inclusive(['\n', '\r']).take(2) {
+        let content = line.trim_end_matches(['\r', '\n']);
+        let indent = content.len() - content.trim_start_matches([' ', '\t', '\x0c']).len();
+        let comment = &content[indent..];
+
+        if !comment.starts_with('#') {
+            if !comment.is_empty() {
+                return None
+            }
+            offset += line.chars().count();
+            continue
+        }
+
+        if let Some(name) = coding_name(comment) {
+            let start = offset + content[..indent].chars().count();
+            return Some((start, start + comment.chars().count(), name))
+        }
+        offset += line.chars().count()
+    }
+
+    None
+}
+
+/// Encoding name following 'coding:' or 'coding=' in a comment.
+fn coding_name(comment: &str) -> Option<String> {
+    comment.match_indices("coding").find_map(|(index, _)| {
+        let rest = &comment[index + 6..];
+        let rest = rest.strip_prefix([':', '='])?.trim_start_matches([' ', '\t']);
+        let name: String = rest.chars().take_while(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.')).collect();
+
+        match name.is_empty() {
+            true => None,
+            _ => Some(name)
+        }
+    })
+}
+
+/// Maps the aliases of the supported encodings to a single name.
+fn normal_encoding_name(name: &str) -> Option<&'static str> {
+    let name = name.to_lowercase().replace('_', "-");
+
+    match name.as_str() {
+        "utf-8" | "utf8" => Some("utf-8"),
+        _ if name.starts_with("utf-8-") => Some("utf-8"),
+        "latin-1" | "latin1" | "iso-8859-1" | "iso8859-1" | "iso-latin-1" | "l1" => Some("iso-8859-1"),
+        _ if name.starts_with("latin-1-") || name.starts_with("iso-8859-1-") || name.starts_with("iso-latin-1-") => Some("iso-8859-1"),
+        "ascii" | "us-ascii" | "646" => Some("ascii"),
+        "cp1252" | "windows-1252" => Some("cp1252"),
+        _ => None
+    }
+}
+
+fn decode_utf8(source: &[u8], errors: &mut Vec<Box<Diagnostic>>) -> String {
+    let mut text = String::with_capacity(source.len());
+    let mut rest = source;
+    let mut index = 0; /* Chars in the text so far */
+
+    loop {
+        match std::str::from_utf8(rest) {
+            Ok(valid) => {
+                text.push_str(valid);
+                return text
+            },
+            Err(error) => {
+                let (valid, invalid) = rest.split_at(error.valid_up_to());
+                let valid = std::str::from_utf8(valid).unwrap();
+                text.push_str(valid);
+                text.push(char::REPLACEMENT_CHARACTER);
+                index += valid.chars().count() as u32;
+
+                let position = source.len() - invalid.len();
+                let reason = match error.error_len() {
+                    None => "unexpected end of data",
+                    Some(_) if (0x80..0xc0).contains(&invalid[0]) || invalid[0] >= 0xf8 => "invalid start byte",
+                    Some(_) => "invalid continuation byte"
+                };
+                errors.push(Box::new(Diagnostic::error(DiagnosticCode::Encoding, index, index + 1, format!("'utf-8' codec can't decode byte 0x{:02x} in position {}: {}", invalid[0], position, reason))));
+                index += 1;
+
+                match error.error_len() {
+                    Some(length) => rest = &invalid[length..],
+                    None => return text
+                }
+            }
+        }
+    }
+}
+
+/// Decodes one char per byte, so byte offsets are also char offsets.
+fn decode_single_byte(source: &[u8], codec: &str, errors: &mut Vec<Box<Diagnostic>>, decode: fn(u8) -> Result<char, &'static str>) -> String {
+    source.iter().enumerate().map(|(position, b)| {
+        decode(*b).unwrap_or_else(|reason| {
+            errors.push(Box::new(Diagnostic::error(DiagnosticCode::Encoding, position as u32, position as u32 + 1, format!("'{}' codec can't decode byte 0x{:02x} in position {}: {}", codec, b, position, reason))));
+            char::REPLACEMENT_CHARACTER
+        })
+    }).collect()
+}
+
+/// Windows-1252 characters for bytes 0x80 to 0x9F, '\0' where the byte is undefined.
+const CP1252_HIGH: [char; 32] = [
+    '\u{20ac}', '\0', '\u{201a}', '\u{0192}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02c6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\0', '\u{017d}', '\0',
+    '\0', '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02dc}', '\u{2122}', '\u{0161}', '\u{203a}', '\u{0153}', '\0', '\u{017e}', '\u{0178}',
+];
+
+
+// Unittests for source decoding ///////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::parser::source_decoder::{coding_declaration, decode_source};
+
+    #[test]
+    fn decode_utf8_with_bom() {
+        let decoded = decode_source(b"\xef\xbb\xbfx = '\xc3\xa9'\n");
+
+        assert_eq!(decoded.encoding, "utf-8");
+        assert_eq!(decoded.text, "\u{feff}x = '\u{e9}'\n");
+        assert!(decoded.errors.is_empty());
+    }
+
+    #[test]
+    fn decode_declared_encodings() {
+        let decoded = decode_source(b"# -*- coding: latin-1 -*-\nx = '\xe9'\n");
+        assert_eq!(decoded.encoding, "iso-8859-1");
+        assert!(decoded.text.ends_with("x = '\u{e9}'\n"));
+
+        let decoded = decode_source(b"#!/usr/bin/env python\n# vim: set fileencoding=cp1252 :\nx = '\x80'\n");
+        assert_eq!(decoded.encoding, "cp1252");
+        assert!(decoded.text.ends_with("x = '\u{20ac}'\n"));
+
+        let decoded = decode_source(b"x = 1\n# coding: latin-1\n");
+        assert_eq!(decoded.encoding, "utf-8");
+    }
+
+    #[test]
+    fn decode_errors_at_char_offsets() {
+        let decoded = decode_source(b"x = '\xc3\xa9\xff'\n");
+        assert_eq!(decoded.errors.len(), 1);
+        assert_eq!((decoded.errors[0].start, decoded.errors[0].end), (6, 7));
+        assert_eq!(decoded.errors[0].message, "'utf-8' codec can't decode byte 0xff in position 7: invalid start byte");
+        assert_eq!(decoded.text, "x = '\u{e9}\u{fffd}'\n");
+
+        let decoded = decode_source(b"# coding: ascii\nx = '\xe9'\n");
+        assert_eq!(decoded.errors[0].start, 21);
+        assert_eq!(decoded.errors[0].message, "'ascii' codec can't decode byte 0xe9 in position 21: ordinal not in range(128)");
+
+        let decoded = decode_source(b"# coding: cp1252\nx = '\x81'\n");
+        assert_eq!(decoded.errors[0].message, "'charmap' codec can't decode byte 0x81 in position 22: character maps to <undefined>");
+
+        let decoded = decode_source(b"# coding: klingon\n");
+        assert_eq!(decoded.errors[0].start, 0);
+        assert_eq!(decoded.errors[0].message, "unknown encoding: klingon");
+
+        let decoded = decode_source("# \u{e9}\u{e9}\n# coding: klingon\n".as_bytes());
+        assert_eq!(decoded.errors[0].start, 5);
+
+        let decoded = decode_source(b"\xef\xbb\xbf# coding: latin-1\n");
+        assert_eq!(decoded.errors[0].start, 1);
+        assert_eq!(decoded.errors[0].message, "encoding problem: iso-8859-1 with BOM");
+    }
+
+    #[test]
+    fn find_coding_declaration() {
+        assert_eq!(coding_declaration("  # coding=utf8\n"), Some((2, 15, String::from("utf8"))));
+        assert_eq!(coding_declaration("\n# -*- coding: utf-8 -*-\n"), Some((1, 24, String::from("utf-8"))));
+        assert_eq!(coding_declaration("# a\n# b\n# coding: utf-8\n"), None);
+        assert_eq!(coding_declaration("# no encoding here\n"), None);
+    }
+}
@@ -1,6 +1,19 @@
 
 use super::token_nodes::Token;
 
+/// How an argument binds to a parameter, for a parameter in a 'def' or 'lambda'. The '/' and bare '*'
+/// markers are separators.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParameterKind {
+    PositionalOnly,
+    PositionalOrKeyword,
+    VarPositional,
+    KeywordOnly,
+    VarKeyword,
+    PositionalOnlySeparator,
+    KeywordOnlySeparator,
+}
+
 #[derive(Clone)]
 pub enum SyntaxNode {
 
@@ -42,6 +55,11 @@ pub enum SyntaxNode {
     NameExprNode(u32, u32, Box<Token>),
     NumberExprNode(u32, u32, Box<Token>),
     StringExprNode(u32, u32, Vec<Box<Token>>),
+    ConcatenatedStringExprNode(u32, u32, Vec<Box<SyntaxNode>>),
+    FormattedStringExprNode(u32, u32, Box<Token>, Vec<Box<SyntaxNode>>, Box<Token>),
+    TemplateStringExprNode(u32, u32, Box<Token>, Vec<Box<SyntaxNode>>, Box<Token>),
+    FormattedStringMiddleNode(u32, u32, Box<Token>),
+    ReplacementFieldNode(u32, u32, Box<Token>, Box<SyntaxNode>, Option<Box<Token>>, Option<Box<Token>>, Option<Box<Token>>, Option<Box<Token>>, Vec<Box<SyntaxNode>>, Box<Token>),
     EllipsisExprNode(u32, u32, Box<Token>),
     NoneExprNode(u32, u32, Box<Token>),
     FalseExprNode(u32, u32, Box<Token>),
@@ -83,6 +101,7 @@ pub enum SyntaxNode {
     GlobalStmtNode(u32, u32, Box<Token>, Vec<Box<SyntaxNode>>, Vec<Box<Token>>),
     NonlocalStmtNode(u32, u32, Box<Token>, Vec<Box<SyntaxNode>>, Vec<Box<Token>>),
     AssertStmtNode(u32, u32, Box<Token>, Box<SyntaxNode>, Option<Box<Token>>, Option<Box<SyntaxNode>>),
+    TypeAliasStmtNode(u32, u32, Box<Token>, Box<Token>, Option<Box<SyntaxNode>>, Box<Token>, Box<SyntaxNode>),
     DottedNameStmtNode(u32, u32, Vec<Box<SyntaxNode>>, Vec<Box<Token>>),
     DottedAsNamesStmtNode(u32, u32, Vec<Box<SyntaxNode>>, Vec<Box<Token>>),
     ImportAsNamesStmtNode(u32, u32, Vec<Box<SyntaxNode>>, Vec<Box<Token>>),
@@ -113,10 +132,10 @@ pub enum SyntaxNode {
     ElseStmtNode(u32, u32, Box<Token>, Box<Token>, Box<SyntaxNode>),
     WhileStmtNode(u32, u32, Box<Token>, Box<SyntaxNode>, Box<Token>, Box<SyntaxNode>, Option<Box<SyntaxNode>>),
     ForStmtNode(u32, u32, Box<Token>, Box<SyntaxNode>, Box<Token>, Box<SyntaxNode>, Box<Token>, Option<Box<Token>>, Box<SyntaxNode>, Option<Box<SyntaxNode>>),
-    WithStmtNode(u32, u32, Box<Token>, Vec<Box<SyntaxNode>>, Vec<Box<Token>>, Box<Token>, Option<Box<Token>>, Box<SyntaxNode>),
+    WithStmtNode(u32, u32, Box<Token>, Option<Box<Token>>, Vec<Box<SyntaxNode>>, Vec<Box<Token>>, Option<Box<Token>>, Box<Token>, Option<Box<Token>>, Box<SyntaxNode>),
     WithItemStmtNode(u32, u32, Box<SyntaxNode>, Option<Box<Token>>, Option<Box<SyntaxNode>>),
     SuiteStmtNode(u32, u32, Box<Token>, Box<Token>, Vec<Box<SyntaxNode>>, Box<Token>),
-    ExceptClauseStmtNode(u32, u32, Box<Token>, Option<Box<SyntaxNode>>, Option<Box<Token>>, Option<Box<SyntaxNode>>),
+    ExceptClauseStmtNode(u32, u32, Box<Token>, Option<Box<Token>>, Option<Box<SyntaxNode>>, Option<Box<Token>>, Option<Box<SyntaxNode>>),
     TryStmtNode(u32, u32, Box<Token>, Box<Token>, Box<SyntaxNode>, Vec<Box<SyntaxNode>>, Option<Box<SyntaxNode>>, Option<Box<Token>>, Option<Box<Token>>, Option<Box<SyntaxNode>>),
     ExceptStmtNode(u32, u32, Box<SyntaxNode>, Box<Token>, Box<SyntaxNode>),
 
@@ -129,20 +148,25 @@ pub enum SyntaxNode {
     DecoratedStmtNode(u32, u32, Box<SyntaxNode>, Box<SyntaxNode>),
     DecoratorsStmtNode(u32, u32, Vec<Box<SyntaxNode>>),
     DecoratorStmtNode(u32, u32, Box<Token>, Box<SyntaxNode>, Option<Box<Token>>, Option<Box<SyntaxNode>>, Option<Box<Token>>, Box<Token>),
-    FuncDefinitionNode(u32, u32, Box<Token>, Box<Token>, Box<SyntaxNode>, Option<Box<Token>>, Option<Box<SyntaxNode>>, Box<Token>, Option<Box<Token>>, Box<SyntaxNode>),
+    FuncDefinitionNode(u32, u32, Box<Token>, Box<Token>, Option<Box<SyntaxNode>>, Box<SyntaxNode>, Option<Box<Token>>, Option<Box<SyntaxNode>>, Box<Token>, Option<Box<Token>>, Box<SyntaxNode>, Option<Box<SyntaxNode>>),
 
 
     FuncTypeStmtNode(u32, u32, Box<Token>, Option<Box<SyntaxNode>>, Box<Token>, Box<Token>, Box<SyntaxNode>),
     TypeListStmtNode(u32, u32, Vec<Box<SyntaxNode>>, Vec<Box<Token>>, Option<Box<Token>>, Option<Box<SyntaxNode>>, Option<Box<Token>>, Option<Box<SyntaxNode>>),
     FuncBodyStmtNode(u32, u32, Box<Token>, Option<Box<Token>>, Option<Box<Token>>, Box<Token>, Vec<Box<SyntaxNode>>, Box<Token>),
-    ClassDefStmtNode(u32, u32, Box<Token>, Box<Token>, Option<Box<Token>>, Option<Box<SyntaxNode>>, Option<Box<Token>>, Box<Token>, Box<SyntaxNode>),
+    ClassDefStmtNode(u32, u32, Box<Token>, Box<Token>, Option<Box<SyntaxNode>>, Option<Box<Token>>, Option<Box<SyntaxNode>>, Option<Box<Token>>, Box<Token>, Box<SyntaxNode>),
     ParametersNode(u32, u32, Box<Token>, Option<Box<SyntaxNode>>, Box<Token>),
     TypedFormalParameterNode(u32, u32, Box<SyntaxNode>, Box<Token>, Box<SyntaxNode>),
 
-    TypedListNode(u32, u32, Vec::<Box<SyntaxNode>>, Vec::<Box<Token>>, Option<Box<Token>>),
+    TypedListNode(u32, u32, Vec::<Box<SyntaxNode>>),
+    ParameterNode(u32, u32, Box<SyntaxNode>, Option<Box<Token>>, Option<Box<Token>>, ParameterKind),
+    SlashElementNode(u32, u32, Box<Token>),
 
+    TypeParamsNode(u32, u32, Box<Token>, Vec<Box<SyntaxNode>>, Vec<Box<Token>>, Box<Token>),
+    TypeParamNode(u32, u32, Option<Box<Token>>, Box<Token>, Option<Box<Token>>, Option<Box<SyntaxNode>>, Option<Box<Token>>, Option<Box<SyntaxNode>>),
 
-    VarListNode(u32, u32, Vec::<Box<SyntaxNode>>, Vec::<Box<Token>>, Option<Box<Token>>),
+
+    VarListNode(u32, u32, Vec::<Box<SyntaxNode>>),
     VarElementNode(u32, u32, Box<SyntaxNode>, Box<Token>, Box<SyntaxNode>),
     VarStarElementNode(u32, u32, Box<Token>, Option<Box<SyntaxNode>>),
     VarPowerElementNode(u32, u32, Box<Token>, Box<SyntaxNode>),
@@ -173,4 +197,7 @@ pub enum SyntaxNode {
     KeyValuePatternNode(u32, u32, Box<SyntaxNode>, Box<Token>, Box<SyntaxNode>),
     DoubleStarPatterNode(u32, u32, Box<Token>, Box<SyntaxNode>),
     KeywordPatternNode(u32, u32, Box<Token>, Box<Token>, Box<SyntaxNode>),
+
+    /* Error nodes */
+    ErrorNode(u32, u32, Vec<Box<SyntaxNode>>, Vec<Box<Token>>),
 }
@@ -1,5 +1,7 @@
 
-use super::trivia_nodes::Trivia;
+use unicode_normalization::UnicodeNormalization;
+
+use super::trivia_nodes::{newline_text, Trivia};
 
 #[derive(Clone)]
 pub enum Token {
@@ -89,11 +91,18 @@ pub enum Token {
     ShiftRightAssignToken(u32, u32, Vec<Box<Trivia>>),
     PowerAssignToken(u32, u32, Vec<Box<Trivia>>),
     EllipsisToken(u32, u32, Vec<Box<Trivia>>),
+    ExclamationToken(u32, u32, Vec<Box<Trivia>>),
 
     /* Literal */
     NameToken(u32, u32, Box<String>, Vec<Box<Trivia>>),
     NumberToken(u32, u32, Box<String>, Vec<Box<Trivia>>),
     StringToken(u32, u32, Vec<Box<String>>, Vec<Box<Trivia>>),
+    FStringStartToken(u32, u32, Box<String>, Vec<Box<Trivia>>),
+    FStringMiddleToken(u32, u32, Box<String>, Vec<Box<Trivia>>),
+    FStringEndToken(u32, u32, Box<String>, Vec<Box<Trivia>>),
+    TStringStartToken(u32, u32, Box<String>, Vec<Box<Trivia>>),
+    TStringMiddleToken(u32, u32, Box<String>, Vec<Box<Trivia>>),
+    TStringEndToken(u32, u32, Box<String>, Vec<Box<Trivia>>),
 
     /* Special */
     NewlineToken(u32, u32, char, char, Vec<Box<Trivia>>),
@@ -102,10 +111,499 @@ pub enum Token {
     TypeCommentToken(u32, u32, Box<str>, Vec<Box<Trivia>>),
     EofToken(u32, u32, Vec<Box<Trivia>>),
 
-    Invalid,
+    /* Text the lexer could not make a valid token of, reported as an error */
+    BadToken(u32, u32, Box<String>, Vec<Box<Trivia>>),
 
     /* Context keywords */
     MatchToken(u32, u32, Vec<Box<Trivia>>),
     CaseToken(u32, u32, Vec<Box<Trivia>>),
+    TypeToken(u32, u32, Vec<Box<Trivia>>),
     DefaultToken(u32, u32, Vec<Box<Trivia>>)
-}
\ No newline at end of file
+}
+
+
+impl Token {
+    /// Start and end offset of the token in the source text, end being exclusive.
+    pub fn span(&self) -> (u32, u32) {
+        match self {
+            Token::FalseToken(s, e, _) |
+            Token::TrueToken(s, e, _) |
+            Token::NoneToken(s, e, _) |
+            Token::AndToken(s, e, _) |
+            Token::AsToken(s, e, _) |
+            Token::AssertToken(s, e, _) |
+            Token::AsyncToken(s, e, _) |
+            Token::AwaitToken(s, e, _) |
+            Token::BreakToken(s, e, _) |
+            Token::ClassToken(s, e, _) |
+            Token::ContinueToken(s, e, _) |
+            Token::DefToken(s, e, _) |
+            Token::DelToken(s, e, _) |
+            Token::ElifToken(s, e, _) |
+            Token::ElseToken(s, e, _) |
+            Token::ExceptToken(s, e, _) |
+            Token::FinallyToken(s, e, _) |
+            Token::ForToken(s, e, _) |
+            Token::FromToken(s, e, _) |
+            Token::GlobalToken(s, e, _) |
+            Token::IfToken(s, e, _) |
+            Token::ImportToken(s, e, _) |
+            Token::InToken(s, e, _) |
+            Token::IsToken(s, e, _) |
+            Token::LambdaToken(s, e, _) |
+            Token::NonlocalToken(s, e, _) |
+            Token::NotToken(s, e, _) |
+            Token::OrToken(s, e, _) |
+            Token::PassToken(s, e, _) |
+            Token::RaiseToken(s, e, _) |
+            Token::ReturnToken(s, e, _) |
+            Token::TryToken(s, e, _) |
+            Token::WhileToken(s, e, _) |
+            Token::WithToken(s, e, _) |
+            Token::YieldToken(s, e, _) |
+            Token::PlusToken(s, e, _) |
+            Token::MinusToken(s, e, _) |
+            Token::MultiplyToken(s, e, _) |
+            Token::DivideToken(s, e, _) |
+            Token::ModuloToken(s, e, _) |
+            Token::FloorDivideToken(s, e, _) |
+            Token::MatricesToken(s, e, _) |
+            Token::ShiftLeftToken(s, e, _) |
+            Token::ShiftRightToken(s, e, _) |
+            Token::BitAndToken(s, e, _) |
+            Token::BitOrToken(s, e, _) |
+            Token::BitXorToken(s, e, _) |
+            Token::BitInvertToken(s, e, _) |
+            Token::ColonAssignToken(s, e, _) |
+            Token::LessThanToken(s, e, _) |
+            Token::GreaterThanToken(s, e, _) |
+            Token::LessOrEqualToken(s, e, _) |
+            Token::GreaterOrEqualToken(s, e, _) |
+            Token::EqualToken(s, e, _) |
+            Token::NotEqualToken(s, e, _) |
+            Token::PowerToken(s, e, _) |
+            Token::LeftParenToken(s, e, _) |
+            Token::RightParenToken(s, e, _) |
+            Token::LeftSquareBracketToken(s, e, _) |
+            Token::RightSquareBracketToken(s, e, _) |
+            Token::LeftCurlyBracketToken(s, e, _) |
+            Token::RightCurlyBracketToken(s, e, _) |
+            Token::SemicolonToken(s, e, _) |
+            Token::CommaToken(s, e, _) |
+            Token::PeriodToken(s, e, _) |
+            Token::ColonToken(s, e, _) |
+            Token::AssignToken(s, e, _) |
+            Token::ArrowToken(s, e, _) |
+            Token::PlusAssignToken(s, e, _) |
+            Token::MinusAssignToken(s, e, _) |
+            Token::MultiplyAssignToken(s, e, _) |
+            Token::DivideAssignToken(s, e, _) |
+            Token::ModuloAssignToken(s, e, _) |
+            Token::AndAssignToken(s, e, _) |
+            Token::XorAssignToken(s, e, _) |
+            Token::OrAssignToken(s, e, _) |
+            Token::FloorDivideAssignToken(s, e, _) |
+            Token::MatricesAssignToken(s, e, _) |
+            Token::ShiftLeftAssignToken(s, e, _) |
+            Token::ShiftRightAssignToken(s, e, _) |
+            Token::PowerAssignToken(s, e, _) |
+            Token::EllipsisToken(s, e, _) |
+            Token::ExclamationToken(s, e, _) |
+            Token::IndentToken(s, e, _) |
+            Token::DedentToken(s, e, _) |
+            Token::EofToken(s, e, _) |
+            Token::MatchToken(s, e, _) |
+            Token::CaseToken(s, e, _) |
+            Token::TypeToken(s, e, _) |
+            Token::DefaultToken(s, e, _) |
+            Token::NameToken(s, e, _, _) |
+            Token::NumberToken(s, e, _, _) |
+            Token::StringToken(s, e, _, _) |
+            Token::FStringStartToken(s, e, _, _) |
+            Token::FStringMiddleToken(s, e, _, _) |
+            Token::FStringEndToken(s, e, _, _) |
+            Token::TStringStartToken(s, e, _, _) |
+            Token::TStringMiddleToken(s, e, _, _) |
+            Token::TStringEndToken(s, e, _, _) |
+            Token::TypeCommentToken(s, e, _, _) |
+            Token::BadToken(s, e, _, _) |
+            Token::NewlineToken(s, e, _, _, _) => (*s, *e)
+        }
+    }
+
+    /// Leading and trailing trivia of the token, leading trivia first and in source order.
+    pub fn trivia(&self) -> &[Box<Trivia>] {
+        match self {
+            Token::FalseToken(_, _, trivia) |
+            Token::TrueToken(_, _, trivia) |
+            Token::NoneToken(_, _, trivia) |
+            Token::AndToken(_, _, trivia) |
+            Token::AsToken(_, _, trivia) |
+            Token::AssertToken(_, _, trivia) |
+            Token::AsyncToken(_, _, trivia) |
+            Token::AwaitToken(_, _, trivia) |
+            Token::BreakToken(_, _, trivia) |
+            Token::ClassToken(_, _, trivia) |
+            Token::ContinueToken(_, _, trivia) |
+            Token::DefToken(_, _, trivia) |
+            Token::DelToken(_, _, trivia) |
+            Token::ElifToken(_, _, trivia) |
+            Token::ElseToken(_, _, trivia) |
+            Token::ExceptToken(_, _, trivia) |
+            Token::FinallyToken(_, _, trivia) |
+            Token::ForToken(_, _, trivia) |
+            Token::FromToken(_, _, trivia) |
+            Token::GlobalToken(_, _, trivia) |
+            Token::IfToken(_, _, trivia) |
+            Token::ImportToken(_, _, trivia) |
+            Token::InToken(_, _, trivia) |
+            Token::IsToken(_, _, trivia) |
+            Token::LambdaToken(_, _, trivia) |
+            Token::NonlocalToken(_, _, trivia) |
+            Token::NotToken(_, _, trivia) |
+            Token::OrToken(_, _, trivia) |
+            Token::PassToken(_, _, trivia) |
+            Token::RaiseToken(_, _, trivia) |
+            Token::ReturnToken(_, _, trivia) |
+            Token::TryToken(_, _, trivia) |
+            Token::WhileToken(_, _, trivia) |
+            Token::WithToken(_, _, trivia) |
+            Token::YieldToken(_, _, trivia) |
+            Token::PlusToken(_, _, trivia) |
+            Token::MinusToken(_, _, trivia) |
+            Token::MultiplyToken(_, _, trivia) |
+            Token::DivideToken(_, _, trivia) |
+            Token::ModuloToken(_, _, trivia) |
+            Token::FloorDivideToken(_, _, trivia) |
+            Token::MatricesToken(_, _, trivia) |
+            Token::ShiftLeftToken(_, _, trivia) |
+            Token::ShiftRightToken(_, _, trivia) |
+            Token::BitAndToken(_, _, trivia) |
+            Token::BitOrToken(_, _, trivia) |
+            Token::BitXorToken(_, _, trivia) |
+            Token::BitInvertToken(_, _, trivia) |
+            Token::ColonAssignToken(_, _, trivia) |
+            Token::LessThanToken(_, _, trivia) |
+            Token::GreaterThanToken(_, _, trivia) |
+            Token::LessOrEqualToken(_, _, trivia) |
+            Token::GreaterOrEqualToken(_, _, trivia) |
+            Token::EqualToken(_, _, trivia) |
+            Token::NotEqualToken(_, _, trivia) |
+            Token::PowerToken(_, _, trivia) |
+            Token::LeftParenToken(_, _, trivia) |
+            Token::RightParenToken(_, _, trivia) |
+            Token::LeftSquareBracketToken(_, _, trivia) |
+            Token::RightSquareBracketToken(_, _, trivia) |
+            Token::LeftCurlyBracketToken(_, _, trivia) |
+            Token::RightCurlyBracketToken(_, _, trivia) |
+            Token::SemicolonToken(_, _, trivia) |
+            Token::CommaToken(_, _, trivia) |
+            Token::PeriodToken(_, _, trivia) |
+            Token::ColonToken(_, _, trivia) |
+            Token::AssignToken(_, _, trivia) |
+            Token::ArrowToken(_, _, trivia) |
+            Token::PlusAssignToken(_, _, trivia) |
+            Token::MinusAssignToken(_, _, trivia) |
+            Token::MultiplyAssignToken(_, _, trivia) |
+            Token::DivideAssignToken(_, _, trivia) |
+            Token::ModuloAssignToken(_, _, trivia) |
+            Token::AndAssignToken(_, _, trivia) |
+            Token::XorAssignToken(_, _, trivia) |
+            Token::OrAssignToken(_, _, trivia) |
+            Token::FloorDivideAssignToken(_, _, trivia) |
+            Token::MatricesAssignToken(_, _, trivia) |
+            Token::ShiftLeftAssignToken(_, _, trivia) |
+            Token::ShiftRightAssignToken(_, _, trivia) |
+            Token::PowerAssignToken(_, _, trivia) |
+            Token::EllipsisToken(_, _, trivia) |
+            Token::ExclamationToken(_, _, trivia) |
+            Token::IndentToken(_, _, trivia) |
+            Token::DedentToken(_, _, trivia) |
+            Token::EofToken(_, _, trivia) |
+            Token::MatchToken(_, _, trivia) |
+            Token::CaseToken(_, _, trivia) |
+            Token::TypeToken(_, _, trivia) |
+            Token::DefaultToken(_, _, trivia) |
+            Token::NameToken(_, _, _, trivia) |
+            Token::NumberToken(_, _, _, trivia) |
+            Token::StringToken(_, _, _, trivia) |
+            Token::FStringStartToken(_, _, _, trivia) |
+            Token::FStringMiddleToken(_, _, _, trivia) |
+            Token::FStringEndToken(_, _, _, trivia) |
+            Token::TStringStartToken(_, _, _, trivia) |
+            Token::TStringMiddleToken(_, _, _, trivia) |
+            Token::TStringEndToken(_, _, _, trivia) |
+            Token::TypeCommentToken(_, _, _, trivia) |
+            Token::BadToken(_, _, _, trivia) |
+            Token::NewlineToken(_, _, _, _, trivia) => trivia
+        }
+    }
+
+    pub fn trivia_mut(&mut self) -> &mut Vec<Box<Trivia>> {
+        match self {
+            Token::FalseToken(_, _, trivia) |
+            Token::TrueToken(_, _, trivia) |
+            Token::NoneToken(_, _, trivia) |
+            Token::AndToken(_, _, trivia) |
+            Token::AsToken(_, _, trivia) |
+            Token::AssertToken(_, _, trivia) |
+            Token::AsyncToken(_, _, trivia) |
+            Token::AwaitToken(_, _, trivia) |
+            Token::BreakToken(_, _, trivia) |
+            Token::ClassToken(_, _, trivia) |
+            Token::ContinueToken(_, _, trivia) |
+            Token::DefToken(_, _, trivia) |
+            Token::DelToken(_, _, trivia) |
+            Token::ElifToken(_, _, trivia) |
+            Token::ElseToken(_, _, trivia) |
+            Token::ExceptToken(_, _, trivia) |
+            Token::FinallyToken(_, _, trivia) |
+            Token::ForToken(_, _, trivia) |
+            Token::FromToken(_, _, trivia) |
+            Token::GlobalToken(_, _, trivia) |
+            Token::IfToken(_, _, trivia) |
+            Token::ImportToken(_, _, trivia) |
+            Token::InToken(_, _, trivia) |
+            Token::IsToken(_, _, trivia) |
+            Token::LambdaToken(_, _, trivia) |
+            Token::NonlocalToken(_, _, trivia) |
+            Token::NotToken(_, _, trivia) |
+            Token::OrToken(_, _, trivia) |
+            Token::PassToken(_, _, trivia) |
+            Token::RaiseToken(_, _, trivia) |
+            Token::ReturnToken(_, _, trivia) |
+            Token::TryToken(_, _, trivia) |
+            Token::WhileToken(_, _, trivia) |
+            Token::WithToken(_, _, trivia) |
+            Token::YieldToken(_, _, trivia) |
+            Token::PlusToken(_, _, trivia) |
+            Token::MinusToken(_, _, trivia) |
+            Token::MultiplyToken(_, _, trivia) |
+            Token::DivideToken(_, _, trivia) |
+            Token::ModuloToken(_, _, trivia) |
+            Token::FloorDivideToken(_, _, trivia) |
+            Token::MatricesToken(_, _, trivia) |
+            Token::ShiftLeftToken(_, _, trivia) |
+            Token::ShiftRightToken(_, _, trivia) |
+            Token::BitAndToken(_, _, trivia) |
+            Token::BitOrToken(_, _, trivia) |
+            Token::BitXorToken(_, _, trivia) |
+            Token::BitInvertToken(_, _, trivia) |
+            Token::ColonAssignToken(_, _, trivia) |
+            Token::LessThanToken(_, _, trivia) |
+            Token::GreaterThanToken(_, _, trivia) |
+            Token::LessOrEqualToken(_, _, trivia) |
+            Token::GreaterOrEqualToken(_, _, trivia) |
+            Token::EqualToken(_, _, trivia) |
+            Token::NotEqualToken(_, _, trivia) |
+            Token::PowerToken(_, _, trivia) |
+            Token::LeftParenToken(_, _, trivia) |
+            Token::RightParenToken(_, _, trivia) |
+            Token::LeftSquareBracketToken(_, _, trivia) |
+            Token::RightSquareBracketToken(_, _, trivia) |
+            Token::LeftCurlyBracketToken(_, _, trivia) |
+            Token::RightCurlyBracketToken(_, _, trivia) |
+            Token::SemicolonToken(_, _, trivia) |
+            Token::CommaToken(_, _, trivia) |
+            Token::PeriodToken(_, _, trivia) |
+            Token::ColonToken(_, _, trivia) |
+            Token::AssignToken(_, _, trivia) |
+            Token::ArrowToken(_, _, trivia) |
+            Token::PlusAssignToken(_, _, trivia) |
+            Token::MinusAssignToken(_, _, trivia) |
+            Token::MultiplyAssignToken(_, _, trivia) |
+            Token::DivideAssignToken(_, _, trivia) |
+            Token::ModuloAssignToken(_, _, trivia) |
+            Token::AndAssignToken(_, _, trivia) |
+            Token::XorAssignToken(_, _, trivia) |
+            Token::OrAssignToken(_, _, trivia) |
+            Token::FloorDivideAssignToken(_, _, trivia) |
+            Token::MatricesAssignToken(_, _, trivia) |
+            Token::ShiftLeftAssignToken(_, _, trivia) |
+            Token::ShiftRightAssignToken(_, _, trivia) |
+            Token::PowerAssignToken(_, _, trivia) |
+            Token::EllipsisToken(_, _, trivia) |
+            Token::ExclamationToken(_, _, trivia) |
+            Token::IndentToken(_, _, trivia) |
+            Token::DedentToken(_, _, trivia) |
+            Token::EofToken(_, _, trivia) |
+            Token::MatchToken(_, _, trivia) |
+            Token::CaseToken(_, _, trivia) |
+            Token::TypeToken(_, _, trivia) |
+            Token::DefaultToken(_, _, trivia) |
+            Token::NameToken(_, _, _, trivia) |
+            Token::NumberToken(_, _, _, trivia) |
+            Token::StringToken(_, _, _, trivia) |
+            Token::FStringStartToken(_, _, _, trivia) |
+            Token::FStringMiddleToken(_, _, _, trivia) |
+            Token::FStringEndToken(_, _, _, trivia) |
+            Token::TStringStartToken(_, _, _, trivia) |
+            Token::TStringMiddleToken(_, _, _, trivia) |
+            Token::TStringEndToken(_, _, _, trivia) |
+            Token::TypeCommentToken(_, _, _, trivia) |
+            Token::BadToken(_, _, _, trivia) |
+            Token::NewlineToken(_, _, _, _, trivia) => trivia
+        }
+    }
+
+    /// Trivia in front of the token, i.e. everything not on the same line after it.
+    pub fn leading_trivia(&self) -> Vec<&Trivia> {
+        let (start, _) = self.span();
+        self.trivia().iter().map(|t| &**t).filter(|t| t.span().1 <= start).collect()
+    }
+
+    /// Trivia after the token up to and including the end of its line.
+    pub fn trailing_trivia(&self) -> Vec<&Trivia> {
+        let (start, _) = self.span();
+        self.trivia().iter().map(|t| &**t).filter(|t| t.span().1 > start).collect()
+    }
+
+    /// True for a token the parser put in during error recovery, as the ':' in 'if x'. It has no
+    /// width and no text. NEWLINE, INDENT, DEDENT and EOF can be empty without being missing.
+    pub fn is_missing(&self) -> bool {
+        let (start, end) = self.span();
+        start == end && !matches!(self, Token::NewlineToken( .. ) | Token::IndentToken( .. ) | Token::DedentToken( .. ) | Token::EofToken( .. ) | Token::BadToken( .. ))
+    }
+
+    /// Source text of the token itself without any trivia.
+    pub fn text(&self) -> String {
+        if self.is_missing() {
+            return String::new()
+        }
+
+        match self {
+            Token::FalseToken( _ , _ , _ ) => String::from("False"),
+            Token::TrueToken( _ , _ , _ ) => String::from("True"),
+            Token::NoneToken( _ , _ , _ ) => String::from("None"),
+            Token::AndToken( _ , _ , _ ) => String::from("and"),
+            Token::AsToken( _ , _ , _ ) => String::from("as"),
+            Token::AssertToken( _ , _ , _ ) => String::from("assert"),
+            Token::AsyncToken( _ , _ , _ ) => String::from("async"),
+            Token::AwaitToken( _ , _ , _ ) => String::from("await"),
+            Token::BreakToken( _ , _ , _ ) => String::from("break"),
+            Token::ClassToken( _ , _ , _ ) => String::from("class"),
+            Token::ContinueToken( _ , _ , _ ) => String::from("continue"),
+            Token::DefToken( _ , _ , _ ) => String::from("def"),
+            Token::DelToken( _ , _ , _ ) => String::from("del"),
+            Token::ElifToken( _ , _ , _ ) => String::from("elif"),
+            Token::ElseToken( _ , _ , _ ) => String::from("else"),
+            Token::ExceptToken( _ , _ , _ ) => String::from("except"),
+            Token::FinallyToken( _ , _ , _ ) => String::from("finally"),
+            Token::ForToken( _ , _ , _ ) => String::from("for"),
+            Token::FromToken( _ , _ , _ ) => String::from("from"),
+            Token::GlobalToken( _ , _ , _ ) => String::from("global"),
+            Token::IfToken( _ , _ , _ ) => String::from("if"),
+            Token::ImportToken( _ , _ , _ ) => String::from("import"),
+            Token::InToken( _ , _ , _ ) => String::from("in"),
+            Token::IsToken( _ , _ , _ ) => String::from("is"),
+            Token::LambdaToken( _ , _ , _ ) => String::from("lambda"),
+            Token::NonlocalToken( _ , _ , _ ) => String::from("nonlocal"),
+            Token::NotToken( _ , _ , _ ) => String::from("not"),
+            Token::OrToken( _ , _ , _ ) => String::from("or"),
+            Token::PassToken( _ , _ , _ ) => String::from("pass"),
+            Token::RaiseToken( _ , _ , _ ) => String::from("raise"),
+            Token::ReturnToken( _ , _ , _ ) => String::from("return"),
+            Token::TryToken( _ , _ , _ ) => String::from("try"),
+            Token::WhileToken( _ , _ , _ ) => String::from("while"),
+            Token::WithToken( _ , _ , _ ) => String::from("with"),
+            Token::YieldToken( _ , _ , _ ) => String::from("yield"),
+            Token::PowerAssignToken( _ , _ , _ ) => String::from("**="),
+            Token::FloorDivideAssignToken( _ , _ , _ ) => String::from("//="),
+            Token::ShiftLeftAssignToken( _ , _ , _ ) => String::from("<<="),
+            Token::ShiftRightAssignToken( _ , _ , _ ) => String::from(">>="),
+            Token::EllipsisToken( _ , _ , _ ) => String::from("..."),
+            Token::ExclamationToken( _ , _ , _ ) => String::from("!"),
+            Token::PowerToken( _ , _ , _ ) => String::from("**"),
+            Token::FloorDivideToken( _ , _ , _ ) => String::from("//"),
+            Token::ShiftLeftToken( _ , _ , _ ) => String::from("<<"),
+            Token::ShiftRightToken( _ , _ , _ ) => String::from(">>"),
+            Token::LessOrEqualToken( _ , _ , _ ) => String::from("<="),
+            Token::GreaterOrEqualToken( _ , _ , _ ) => String::from(">="),
+            Token::EqualToken( _ , _ , _ ) => String::from("=="),
+            Token::NotEqualToken( _ , _ , _ ) => String::from("!="),
+            Token::ArrowToken( _ , _ , _ ) => String::from("->"),
+            Token::ColonAssignToken( _ , _ , _ ) => String::from(":="),
+            Token::PlusAssignToken( _ , _ , _ ) => String::from("+="),
+            Token::MinusAssignToken( _ , _ , _ ) => String::from("-="),
+            Token::MultiplyAssignToken( _ , _ , _ ) => String::from("*="),
+            Token::DivideAssignToken( _ , _ , _ ) => String::from("/="),
+            Token::ModuloAssignToken( _ , _ , _ ) => String::from("%="),
+            Token::MatricesAssignToken( _ , _ , _ ) => String::from("@="),
+            Token::AndAssignToken( _ , _ , _ ) => String::from("&="),
+            Token::OrAssignToken( _ , _ , _ ) => String::from("|="),
+            Token::XorAssignToken( _ , _ , _ ) => String::from("^="),
+            Token::PlusToken( _ , _ , _ ) => String::from("+"),
+            Token::MinusToken( _ , _ , _ ) => String::from("-"),
+            Token::MultiplyToken( _ , _ , _ ) => String::from("*"),
+            Token::DivideToken( _ , _ , _ ) => String::from("/"),
+            Token::ModuloToken( _ , _ , _ ) => String::from("%"),
+            Token::MatricesToken( _ , _ , _ ) => String::from("@"),
+            Token::BitAndToken( _ , _ , _ ) => String::from("&"),
+            Token::BitOrToken( _ , _ , _ ) => String::from("|"),
+            Token::BitXorToken( _ , _ , _ ) => String::from("^"),
+            Token::BitInvertToken( _ , _ , _ ) => String::from("~"),
+            Token::LessThanToken( _ , _ , _ ) => String::from("<"),
+            Token::GreaterThanToken( _ , _ , _ ) => String::from(">"),
+            Token::LeftParenToken( _ , _ , _ ) => String::from("("),
+            Token::RightParenToken( _ , _ , _ ) => String::from(")"),
+            Token::LeftSquareBracketToken( _ , _ , _ ) => String::from("["),
+            Token::RightSquareBracketToken( _ , _ , _ ) => String::from("]"),
+            Token::LeftCurlyBracketToken( _ , _ , _ ) => String::from("{"),
+            Token::RightCurlyBracketToken( _ , _ , _ ) => String::from("}"),
+            Token::CommaToken( _ , _ , _ ) => String::from(","),
+            Token::ColonToken( _ , _ , _ ) => String::from(":"),
+            Token::SemicolonToken( _ , _ , _ ) => String::from(";"),
+            Token::PeriodToken( _ , _ , _ ) => String::from("."),
+            Token::AssignToken( _ , _ , _ ) => String::from("="),
+            Token::MatchToken( _ , _ , _ ) => String::from("match"),
+            Token::CaseToken( _ , _ , _ ) => String::from("case"),
+            Token::TypeToken( _ , _ , _ ) => String::from("type"),
+            Token::DefaultToken( _ , _ , _ ) => String::from("_"),
+            Token::NameToken( _ , _ , text, _ ) |
+            Token::NumberToken( _ , _ , text, _ ) |
+            Token::FStringStartToken( _ , _ , text, _ ) |
+            Token::FStringMiddleToken( _ , _ , text, _ ) |
+            Token::FStringEndToken( _ , _ , text, _ ) |
+            Token::TStringStartToken( _ , _ , text, _ ) |
+            Token::TStringMiddleToken( _ , _ , text, _ ) |
+            Token::TStringEndToken( _ , _ , text, _ ) |
+            Token::BadToken( _ , _ , text, _ ) => text.to_string(),
+            Token::StringToken( _ , _ , parts, _ ) => parts.iter().map(|part| part.as_str()).collect(),
+            Token::TypeCommentToken( _ , _ , text, _ ) => text.to_string(),
+            Token::NewlineToken( _ , _ , ch1, ch2, _ ) => newline_text(None, *ch1, *ch2),
+            Token::IndentToken( _ , _ , _ ) |
+            Token::DedentToken( _ , _ , _ ) |
+            Token::EofToken( _ , _ , _ ) => String::new()
+        }
+    }
+
+    /// Identifier of a name token in NFKC normal form, as Python compares identifiers, so that
+    /// 'ﬁle' and 'file' are the same name.
+    pub fn normalized_name(&self) -> Option<String> {
+        match self {
+            Token::NameToken( _ , _ , text, _ ) if text.is_ascii() => Some(text.to_string()),
+            Token::NameToken( _ , _ , text, _ ) => Some(text.nfkc().collect()),
+            _ => None
+        }
+    }
+
+    /// Start and end offset of the token with its leading and trailing trivia.
+    pub fn full_span(&self) -> (u32, u32) {
+        let (start, end) = self.span();
+        let trivia = self.trivia();
+        (trivia.first().map_or(start, |t| t.span().0.min(start)), trivia.last().map_or(end, |t| t.span().1.max(end)))
+    }
+
+    /// Source text of the token including its leading and trailing trivia.
+    pub fn full_text(&self) -> String {
+        let mut text = String::new();
+        for trivia in self.leading_trivia() {
+            text.push_str(&trivia.text())
+        }
+        text.push_str(&self.text());
+        for trivia in self.trailing_trivia() {
+            text.push_str(&trivia.text())
+        }
+        text
+    }
+}
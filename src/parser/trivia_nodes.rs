@@ -1,5 +1,44 @@
 
 #[derive(Clone)]
 pub enum Trivia {
-    Whitespace,
+    Whitespace(u32, u32, Box<String>),
+    Comment(u32, u32, Box<String>),
+    Newline(u32, u32, char, char),
+    LineContinuation(u32, u32, char, char),
+    FormFeed(u32, u32),
+    ByteOrderMark(u32, u32),
+    EncodingDeclaration(u32, u32, Box<String>),
+}
+
+impl Trivia {
+    /// Start and end offset of the trivia in the source text, end being exclusive.
+    pub fn span(&self) -> (u32, u32) {
+        match self {
+            Trivia::Whitespace(s, e, _) |
+            Trivia::Comment(s, e, _) |
+            Trivia::Newline(s, e, _, _) |
+            Trivia::LineContinuation(s, e, _, _) |
+            Trivia::FormFeed(s, e) |
+            Trivia::ByteOrderMark(s, e) |
+            Trivia::EncodingDeclaration(s, e, _) => (*s, *e)
+        }
+    }
+
+    /// The exact source text covered by the trivia.
+    pub fn text(&self) -> String {
+        match self {
+            Trivia::Whitespace(_, _, text) |
+            Trivia::Comment(_, _, text) |
+            Trivia::EncodingDeclaration(_, _, text) => text.to_string(),
+            Trivia::Newline(_, _, ch1, ch2) => newline_text(None, *ch1, *ch2),
+            Trivia::LineContinuation(_, _, ch1, ch2) => newline_text(Some('\\'), *ch1, *ch2),
+            Trivia::FormFeed(_, _) => String::from("\x0c"),
+            Trivia::ByteOrderMark(_, _) => String::from("\u{feff}")
+        }
+    }
+}
+
+/// Builds the text of a line break from the characters stored for it, '\0' marking absence.
+pub fn newline_text(prefix: Option<char>, ch1: char, ch2: char) -> String {
+    prefix.into_iter().chain([ch1, ch2]).filter(|ch| *ch != '\0').collect()
 }